@@ -5,27 +5,26 @@
 
 use ineed::prelude::*;
 
-#[derive(Debug)]
+#[derive(ineed::Selectable)]
 enum Level {
     Good,
     Medium,
     Bad,
+    Custom(#[ineed(msg = "Your custom level")] u8),
 }
 
 fn main() -> anyhow::Result<()> {
     let (username, level) = ineed::written::<String>("Your username")
-        .then(ineed::selected(
-            "Your level",
-            [
-                ("Good", Level::Good),
-                ("Medium", Level::Medium),
-                ("Bad", Level::Bad),
-            ],
-        ))
+        .then(Level::prompt_select("Your level"))
         .prompt()?;
 
     println!("username={username}");
-    println!("level={level:?}");
+    match level {
+        Level::Good => println!("level=good"),
+        Level::Medium => println!("level=medium"),
+        Level::Bad => println!("level=bad"),
+        Level::Custom(level) => println!("level={level}"),
+    }
 
     Ok(())
 }
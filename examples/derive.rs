@@ -0,0 +1,29 @@
+//! This example shows how you can prompt a whole struct with the derive macro.
+//!
+//! Each field is prompted one after another, and the chain outputs the struct directly.
+
+use ineed::prelude::*;
+
+#[derive(ineed::Promptable)]
+#[ineed(fmt = ineed::fmt().input_prefix(">> "))]
+struct Config {
+    #[ineed(msg = "Your username")]
+    username: String,
+    #[ineed(default = 8080, until = |port| *port >= 1024, until_msg = "use a non-privileged port")]
+    port: u16,
+    #[ineed(selected = [("Low", 1), ("Medium", 5), ("High", 10)])]
+    verbosity: u8,
+    #[ineed(max_tries = 3, max_tries_fallback = false)]
+    dry_run: bool,
+}
+
+fn main() -> anyhow::Result<()> {
+    let config = Config::prompt()?;
+
+    println!("username={}", config.username);
+    println!("port={}", config.port);
+    println!("verbosity={}", config.verbosity);
+    println!("dry_run={}", config.dry_run);
+
+    Ok(())
+}
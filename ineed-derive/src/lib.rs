@@ -0,0 +1,33 @@
+//! Derive macros for the [`ineed`](https://docs.rs/ineed) crate.
+//!
+//! You shouldn't use this crate directly, but enable the `derive` feature of the `ineed` crate
+//! instead, which re-exports the macros.
+
+use proc_macro::TokenStream;
+use syn::{DeriveInput, parse_macro_input};
+
+mod promptable;
+mod selectable;
+mod utils;
+
+/// Derives the `FromPrompt` trait for a struct, to prompt the user for each of its fields.
+///
+/// See the documentation of the `ineed` crate for more information.
+#[proc_macro_derive(Promptable, attributes(ineed))]
+pub fn derive_promptable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    promptable::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives the `Selectable` trait for an enum, to prompt the user to choose one of its variants.
+///
+/// See the documentation of the `ineed` crate for more information.
+#[proc_macro_derive(Selectable, attributes(ineed))]
+pub fn derive_selectable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    selectable::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
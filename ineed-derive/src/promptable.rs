@@ -0,0 +1,224 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Expr, Field, Fields, LitInt, LitStr, Type, spanned::Spanned as _};
+
+use crate::utils::{humanize, parse_attrs};
+
+/// The attributes of a field, e.g. `#[ineed(msg = "Your age", max_tries = 3)]`.
+#[derive(Default)]
+struct FieldAttrs {
+    msg: Option<LitStr>,
+    fmt: Option<Expr>,
+    default: Option<Expr>,
+    selected: Option<Expr>,
+    until: Option<Expr>,
+    until_msg: Option<LitStr>,
+    key: Option<LitStr>,
+    max_tries: Option<LitInt>,
+    max_tries_fallback: Option<Expr>,
+}
+
+impl FieldAttrs {
+    fn parse(field: &Field) -> syn::Result<Self> {
+        let mut attrs = Self::default();
+        parse_attrs(&field.attrs, |meta| {
+            let value = meta.value()?;
+            match meta.path.get_ident().map(ToString::to_string).as_deref() {
+                Some("msg") => attrs.msg = Some(value.parse()?),
+                Some("fmt") => attrs.fmt = Some(value.parse()?),
+                Some("default") => attrs.default = Some(value.parse()?),
+                Some("selected") => attrs.selected = Some(value.parse()?),
+                Some("until") => attrs.until = Some(value.parse()?),
+                Some("until_msg") => attrs.until_msg = Some(value.parse()?),
+                Some("key") => attrs.key = Some(value.parse()?),
+                Some("max_tries") => attrs.max_tries = Some(value.parse()?),
+                Some("max_tries_fallback") => attrs.max_tries_fallback = Some(value.parse()?),
+                _ => return Err(meta.error("unsupported ineed attribute")),
+            }
+            Ok(())
+        })?;
+
+        if attrs.until_msg.is_some() && attrs.until.is_none() {
+            return Err(syn::Error::new(
+                attrs.until_msg.span(),
+                "the `until_msg` attribute requires the `until` attribute",
+            ));
+        }
+
+        match (&attrs.max_tries, &attrs.max_tries_fallback) {
+            (Some(max), None) => {
+                return Err(syn::Error::new(
+                    max.span(),
+                    "the `max_tries` attribute requires the `max_tries_fallback` attribute",
+                ));
+            }
+            (None, Some(fallback)) => {
+                return Err(syn::Error::new(
+                    fallback.span(),
+                    "the `max_tries_fallback` attribute requires the `max_tries` attribute",
+                ));
+            }
+            _ => {}
+        }
+
+        Ok(attrs)
+    }
+}
+
+/// The attributes of the struct, e.g. `#[ineed(fmt = ineed::fmt().input_prefix(">> "))]`.
+#[derive(Default)]
+struct StructAttrs {
+    fmt: Option<Expr>,
+}
+
+impl StructAttrs {
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut attrs = Self::default();
+        parse_attrs(&input.attrs, |meta| {
+            if meta.path.is_ident("fmt") {
+                attrs.fmt = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported ineed attribute"))
+            }
+        })?;
+        Ok(attrs)
+    }
+}
+
+fn is_bool(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("bool"))
+}
+
+/// Returns the expression of the promptable for the provided field.
+fn field_promptable(
+    field: &Field, index: usize, default_msg: Option<&LitStr>,
+) -> syn::Result<TokenStream> {
+    let attrs = FieldAttrs::parse(field)?;
+    let ty = &field.ty;
+
+    let msg = match (&attrs.msg, &field.ident) {
+        (Some(msg), _) => msg.clone(),
+        (None, Some(ident)) => LitStr::new(&humanize(ident), ident.span()),
+        (None, None) if let Some(msg) = default_msg => msg.clone(),
+        (None, None) => {
+            return Err(syn::Error::new(
+                field.span(),
+                format!("missing `#[ineed(msg = \"...\")]` attribute for the field {index}"),
+            ));
+        }
+    };
+
+    let mut prompt = match &attrs.selected {
+        Some(list) => quote!(::ineed::selected(#msg, #list)),
+        None if is_bool(ty) => quote!(::ineed::bool(#msg)),
+        None => quote!(::ineed::written::<#ty>(#msg)),
+    };
+
+    if let Some(default) = &attrs.default {
+        prompt = quote!(#prompt.default(#default));
+    }
+    if let Some(until) = &attrs.until {
+        prompt = quote!(#prompt.until(#until));
+        if let Some(msg) = &attrs.until_msg {
+            prompt = quote!(#prompt.msg(#msg));
+        }
+    }
+    if let Some(fmt) = &attrs.fmt {
+        prompt = quote!(#prompt.fmt(#fmt));
+    }
+    if let Some(key) = &attrs.key {
+        prompt = quote!(#prompt.key(#key));
+    }
+    if let (Some(max), Some(fallback)) = (&attrs.max_tries, &attrs.max_tries_fallback) {
+        prompt = quote! {
+            #prompt
+                .max_tries(#max)
+                .map(|out: ::core::result::Result<#ty, ::ineed::MaxTriesExceeded>| {
+                    out.unwrap_or_else(|_| #fallback)
+                })
+        };
+    }
+
+    Ok(prompt)
+}
+
+/// Returns the expression of the promptable that prompts each field one after another, and builds
+/// the value with the provided path (e.g. `Self` or `Self::Variant`).
+///
+/// Unnamed fields without a message take the provided default one, if any.
+pub(crate) fn fields_promptable(
+    fields: &Fields, path: TokenStream, default_msg: Option<&LitStr>,
+) -> syn::Result<TokenStream> {
+    let prompts = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| field_promptable(field, i, default_msg))
+        .collect::<syn::Result<Vec<_>>>()?;
+    let types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let bindings = (0..types.len())
+        .map(|i| format_ident!("__field{i}"))
+        .collect::<Vec<_>>();
+
+    // The raw output of the chain is nested couples, e.g. `((A, B), C)`. We specify it explicitly
+    // for each call to `then`, so there is no limit on the amount of fields.
+    let mut chain = prompts[0].clone();
+    let mut out_ty = {
+        let ty = types[0];
+        quote!(#ty)
+    };
+    let mut out_pat = {
+        let binding = &bindings[0];
+        quote!(#binding)
+    };
+    for ((prompt, ty), binding) in prompts.iter().zip(&types).zip(&bindings).skip(1) {
+        out_ty = quote!((#out_ty, #ty));
+        out_pat = quote!((#out_pat, #binding));
+        chain = quote!(#chain.then::<_, #out_ty>(#prompt));
+    }
+
+    let construct = match fields {
+        Fields::Named(fields) => {
+            let idents = fields.named.iter().map(|field| &field.ident);
+            quote!(#path { #(#idents: #bindings),* })
+        }
+        _ => quote!(#path(#(#bindings),*)),
+    };
+
+    Ok(quote!(#chain.map(|#out_pat: #out_ty| #construct)))
+}
+
+pub(crate) fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "the Promptable derive macro only supports structs",
+        ));
+    };
+    if data.fields.is_empty() {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "the Promptable derive macro requires at least one field",
+        ));
+    }
+
+    let struct_attrs = StructAttrs::parse(&input)?;
+
+    let mut prompt = fields_promptable(&data.fields, quote!(Self), None)?;
+    if let Some(fmt) = &struct_attrs.fmt {
+        prompt = quote!(#prompt.fmt(#fmt));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::ineed::FromPrompt for #name #ty_generics #where_clause {
+            fn promptable() -> impl ::ineed::Promptable<Output = Self> {
+                use ::ineed::prelude::*;
+                #prompt
+            }
+        }
+    })
+}
@@ -0,0 +1,133 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Data, DeriveInput, Expr, Fields, LitStr, Variant};
+
+use crate::{promptable::fields_promptable, utils::parse_attrs};
+
+/// The attributes of a variant, e.g. `#[ineed(label = "So-so", default)]`.
+#[derive(Default)]
+struct VariantAttrs {
+    label: Option<LitStr>,
+    default: bool,
+}
+
+impl VariantAttrs {
+    fn parse(variant: &Variant) -> syn::Result<Self> {
+        let mut attrs = Self::default();
+        parse_attrs(&variant.attrs, |meta| {
+            if meta.path.is_ident("label") {
+                attrs.label = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("default") {
+                attrs.default = true;
+            } else {
+                return Err(meta.error("unsupported ineed attribute"));
+            }
+            Ok(())
+        })?;
+        Ok(attrs)
+    }
+}
+
+/// The attributes of the enum, e.g. `#[ineed(label_match = ineed::LabelMatch::Prefix)]`.
+#[derive(Default)]
+struct EnumAttrs {
+    label_match: Option<Expr>,
+}
+
+impl EnumAttrs {
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut attrs = Self::default();
+        parse_attrs(&input.attrs, |meta| {
+            if meta.path.is_ident("label_match") {
+                attrs.label_match = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported ineed attribute"))
+            }
+        })?;
+        Ok(attrs)
+    }
+}
+
+pub(crate) fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "the Selectable derive macro only supports enums",
+        ));
+    };
+    if data.variants.is_empty() {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "the Selectable derive macro requires at least one variant",
+        ));
+    }
+
+    let enum_attrs = EnumAttrs::parse(&input)?;
+    let has_payload = data.variants.iter().any(|v| !v.fields.is_empty());
+
+    let mut default = None;
+    let mut items = Vec::with_capacity(data.variants.len());
+    for (i, variant) in data.variants.iter().enumerate() {
+        let attrs = VariantAttrs::parse(variant)?;
+        if attrs.default {
+            if default.is_some() {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "only one variant can be the default one",
+                ));
+            }
+            default = Some(i);
+        }
+
+        let ident = &variant.ident;
+        let label = attrs
+            .label
+            .unwrap_or_else(|| LitStr::new(&ident.to_string(), ident.span()));
+
+        let value = match &variant.fields {
+            Fields::Unit if has_payload => quote!(::ineed::Variant::Value(Self::#ident)),
+            Fields::Unit => quote!(Self::#ident),
+            fields => {
+                let prompt = fields_promptable(fields, quote!(Self::#ident), Some(&label))?;
+                quote! {
+                    ::ineed::Variant::Payload(|read, write, fmt| {
+                        use ::ineed::prelude::*;
+                        #prompt.prompt_once(read, write, &::core::convert::From::from(*fmt))
+                    })
+                }
+            }
+        };
+
+        items.push(quote!((#label, #value)));
+    }
+
+    let mut prompt = if has_payload {
+        quote!(::ineed::variants(msg, [#(#items),*]))
+    } else {
+        quote!(::ineed::selected(msg, [#(#items),*]))
+    };
+    if let Some(default) = default {
+        prompt = quote!(#prompt.default(#default));
+    }
+    if let Some(label_match) = &enum_attrs.label_match {
+        prompt = quote!(#prompt.label_match(#label_match));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::ineed::Selectable for #name #ty_generics #where_clause {
+            fn prompt_select<'__fmt>(
+                msg: &str,
+            ) -> impl ::ineed::Promptable<
+                Output = Self,
+                FmtRules = ::ineed::format::rules::SelectedFmtRules<'__fmt>,
+            > {
+                #prompt
+            }
+        }
+    })
+}
@@ -0,0 +1,23 @@
+use syn::{Attribute, Ident, meta::ParseNestedMeta};
+
+/// Calls the provided function for each item of the `#[ineed(...)]` attributes.
+pub(crate) fn parse_attrs<F>(attrs: &[Attribute], mut f: F) -> syn::Result<()>
+where
+    F: FnMut(ParseNestedMeta<'_>) -> syn::Result<()>,
+{
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("ineed"))
+        .try_for_each(|attr| attr.parse_nested_meta(&mut f))
+}
+
+/// Turns an identifier into a readable message, e.g. `max_connections` into `Max connections`.
+pub(crate) fn humanize(ident: &Ident) -> String {
+    let ident = ident.to_string();
+    let ident = ident.strip_prefix("r#").unwrap_or(&ident).replace('_', " ");
+    let mut chars = ident.trim().chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,481 @@
+//! Non-interactive answers, to run the prompts without a human.
+//!
+//! A prompt can be given a stable key with the [`Promptable::key`] method. When some [`Answers`]
+//! are installed, the keyed prompts take their answer from them instead of reading the input
+//! stream. The answer is interpreted exactly like a line entered by the user, so it is parsed with
+//! the same rules as the wrapped promptable (e.g. `yes` or `no` for a [boolean](crate::bool())
+//! prompt, the index of the item for a [selected](crate::selected()) prompt, etc).
+//!
+//! ```no_run
+//! # use ineed::prelude::*;
+//! use ineed::answers::Answers;
+//!
+//! let answers = Answers::new()
+//!   .answer("name", "foo")
+//!   .answer("port", "8080");
+//!
+//! let (name, port, verbose) = answers
+//!   .prompt(
+//!     ineed::written::<String>("Your name")
+//!       .key("name")
+//!       .then(ineed::written::<u16>("Port").key("port"))
+//!       .then(ineed::bool("Verbose?").key("verbose")),
+//!   )
+//!   .unwrap();
+//! ```
+//!
+//! In this example, the user is only asked whether to be verbose or not, because the answer is
+//! missing. You can make missing answers an error instead, with the [`Answers::on_missing`]
+//! method.
+//!
+//! If an answer is rejected by its prompt, an error of kind
+//! [`InvalidData`](io::ErrorKind::InvalidData) is returned, instead of prompting the user again.
+//!
+//! The answers can also be loaded from a TOML file with the `toml` feature, or from a JSON file
+//! with the `json` feature. The keys of nested tables are joined with a dot (e.g. `db.port`),
+//! and arrays are joined with a comma, so they can be used with a [separated](crate::separated())
+//! prompt.
+//!
+//! # Asynchronous prompts
+//!
+//! The answers installed with [`Answers::scope`] are only visible to the current thread, while
+//! the function runs. An asynchronous prompt reads them when its future is polled, so the future
+//! must be polled inside the scope, on the same thread. This isn't the case when the future is
+//! awaited outside of the function, or when its task resumes on another worker of a
+//! multi-threaded runtime. In these cases, give the answers to each keyed prompt explicitly, with
+//! the [`Keyed::answers`](crate::Keyed::answers) method:
+//!
+//! ```no_run
+//! # use ineed::prelude::*;
+//! # #[cfg(feature = "async")]
+//! # async fn run() -> std::io::Result<()> {
+//! use ineed::answers::Answers;
+//!
+//! let answers = Answers::new().answer("port", "8080");
+//! let port = ineed::written::<u16>("Port")
+//!   .key("port")
+//!   .answers(&answers)
+//!   .prompt_async()
+//!   .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(any(feature = "toml", feature = "json"))]
+use std::path::Path;
+use std::{cell::RefCell, collections::HashMap, io, sync::Arc};
+
+use crate::Promptable;
+
+/// The behavior of the keyed prompts when their answer is missing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OnMissing {
+    /// Prompts the user interactively.
+    #[default]
+    Prompt,
+    /// Returns an error of kind [`NotFound`](io::ErrorKind::NotFound).
+    Error,
+}
+
+/// A set of answers, bound to the keys of the prompts.
+///
+/// The answers are shared between their clones, so cloning a set is cheap.
+///
+/// See the [module documentation](self) for more information.
+#[derive(Debug, Clone, Default)]
+pub struct Answers {
+    map: Arc<HashMap<String, String>>,
+    on_missing: OnMissing,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Answers>> = const { RefCell::new(None) };
+}
+
+/// Restores the previously installed answers when dropped.
+struct ScopeGuard(Option<Answers>);
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+    }
+}
+
+impl Answers {
+    /// Returns an empty set of answers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an answer for the provided key.
+    pub fn answer<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.insert(key, value);
+        self
+    }
+
+    /// Adds an answer for the provided key, without consuming the set.
+    ///
+    /// Returns the previous answer of the key, if any.
+    pub fn insert<K: Into<String>, V: Into<String>>(&mut self, key: K, value: V) -> Option<String> {
+        Arc::make_mut(&mut self.map).insert(key.into(), value.into())
+    }
+
+    /// Returns the answer of the provided key, if any.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.map.get(key).map(String::as_str)
+    }
+
+    /// Sets the behavior of the keyed prompts when their answer is missing.
+    ///
+    /// By default, the user is prompted interactively.
+    pub fn on_missing(mut self, on_missing: OnMissing) -> Self {
+        self.on_missing = on_missing;
+        self
+    }
+
+    /// Parses the answers from a TOML document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidData`](io::ErrorKind::InvalidData) if the document is
+    /// invalid.
+    #[cfg(feature = "toml")]
+    #[cfg_attr(nightly, doc(cfg(feature = "toml")))]
+    pub fn from_toml(s: &str) -> io::Result<Self> {
+        fn flatten(answers: &mut Answers, prefix: String, value: toml::Value) {
+            let value = match value {
+                toml::Value::Table(table) => {
+                    for (key, value) in table {
+                        flatten(answers, join_key(&prefix, &key), value);
+                    }
+                    return;
+                }
+                toml::Value::String(s) => s,
+                toml::Value::Array(values) => values
+                    .into_iter()
+                    .map(|value| match value {
+                        toml::Value::String(s) => s,
+                        other => other.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(","),
+                other => other.to_string(),
+            };
+            answers.insert(prefix, value);
+        }
+
+        let table = s
+            .parse::<toml::Table>()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let mut answers = Self::new();
+        flatten(&mut answers, String::new(), toml::Value::Table(table));
+        Ok(answers)
+    }
+
+    /// Parses the answers from a JSON document.
+    ///
+    /// The document must be a JSON object. Null values are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidData`](io::ErrorKind::InvalidData) if the document is
+    /// invalid.
+    #[cfg(feature = "json")]
+    #[cfg_attr(nightly, doc(cfg(feature = "json")))]
+    pub fn from_json(s: &str) -> io::Result<Self> {
+        use serde_json::Value;
+
+        fn flatten(answers: &mut Answers, prefix: String, value: Value) {
+            let value = match value {
+                Value::Null => return,
+                Value::Object(object) => {
+                    for (key, value) in object {
+                        flatten(answers, join_key(&prefix, &key), value);
+                    }
+                    return;
+                }
+                Value::String(s) => s,
+                Value::Array(values) => values
+                    .into_iter()
+                    .map(|value| match value {
+                        Value::String(s) => s,
+                        other => other.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(","),
+                other => other.to_string(),
+            };
+            answers.insert(prefix, value);
+        }
+
+        let object = serde_json::from_str::<serde_json::Map<String, Value>>(s)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let mut answers = Self::new();
+        flatten(&mut answers, String::new(), Value::Object(object));
+        Ok(answers)
+    }
+
+    /// Reads the answers from a file.
+    ///
+    /// The format of the file is determined by its extension: `.toml` files require the `toml`
+    /// feature, and `.json` files require the `json` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file couldn't be read, or an error of kind
+    /// [`InvalidData`](io::ErrorKind::InvalidData) if its content is invalid. If the format of
+    /// the file isn't supported, an error of kind [`Unsupported`](io::ErrorKind::Unsupported)
+    /// is returned.
+    #[cfg(any(feature = "toml", feature = "json"))]
+    #[cfg_attr(nightly, doc(cfg(any(feature = "toml", feature = "json"))))]
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            #[cfg(feature = "toml")]
+            Some("toml") => Self::from_toml(&content),
+            #[cfg(feature = "json")]
+            Some("json") => Self::from_json(&content),
+            _ => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("unsupported answers file format: {}", path.display()),
+            )),
+        }
+    }
+
+    /// Installs the answers on the current thread while running the provided function.
+    ///
+    /// The keyed prompts run by the function take their answer from this set. The previously
+    /// installed answers, if any, are restored afterwards.
+    ///
+    /// See the [module documentation](self#asynchronous-prompts) for the asynchronous prompts.
+    pub fn scope<F: FnOnce() -> T, T>(&self, f: F) -> T {
+        let previous = CURRENT.with(|current| current.replace(Some(self.clone())));
+        let _guard = ScopeGuard(previous);
+        f()
+    }
+
+    /// Prompts the provided promptable with these answers, using the standard input and output
+    /// for the missing answers.
+    pub fn prompt<P: Promptable>(&self, mut promptable: P) -> io::Result<P::Output> {
+        self.scope(|| promptable.prompt())
+    }
+}
+
+impl From<HashMap<String, String>> for Answers {
+    fn from(map: HashMap<String, String>) -> Self {
+        Self {
+            map: Arc::new(map),
+            on_missing: OnMissing::default(),
+        }
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Answers {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self::from(
+            iter.into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect::<HashMap<_, _>>(),
+        )
+    }
+}
+
+#[cfg(any(feature = "toml", feature = "json"))]
+fn join_key(prefix: &str, key: &str) -> String {
+    match prefix {
+        "" => key.to_owned(),
+        _ => format!("{prefix}.{key}"),
+    }
+}
+
+/// Returns the answer of the provided key from the provided answers, or from the installed ones
+/// if `None`.
+///
+/// Returns `Ok(None)` if the user must be prompted interactively, i.e. if no answers are
+/// installed, or if the answer is missing and the user must be prompted in such case.
+pub(crate) fn resolve(answers: Option<&Answers>, key: &str) -> io::Result<Option<String>> {
+    let resolve = |answers: Option<&Answers>| match answers {
+        None => Ok(None),
+        Some(answers) => match (answers.get(key), answers.on_missing) {
+            (Some(answer), _) => Ok(Some(answer.to_owned())),
+            (None, OnMissing::Prompt) => Ok(None),
+            (None, OnMissing::Error) => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("missing answer for the key \"{key}\""),
+            )),
+        },
+    };
+
+    match answers {
+        Some(answers) => resolve(Some(answers)),
+        None => CURRENT.with(|current| resolve(current.borrow().as_ref())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{Answers, OnMissing};
+    use crate::prelude::*;
+
+    #[test]
+    fn resolve_chain() -> anyhow::Result<()> {
+        let answers = Answers::new()
+            .answer("name", "foo")
+            .answer("level", "2")
+            .answer("tags", "a,b");
+        let (name, age, level, tags, ok) = answers.scope(|| {
+            crate::written::<String>("Name")
+                .key("name")
+                .then(crate::written::<u8>("Age").key("age"))
+                .then(crate::selected("Level", [("Low", 1), ("High", 2)]).key("level"))
+                .then(crate::separated::<Vec<String>, _>("Tags", ",").key("tags"))
+                .then(crate::bool("Ok?"))
+                .prompt_with("32\nyes\n".as_bytes(), std::io::empty())
+        })?;
+        assert_eq!(name, "foo");
+        assert_eq!(age, 32);
+        assert_eq!(level, 2);
+        assert_eq!(tags, ["a", "b"]);
+        assert!(ok);
+
+        Ok(())
+    }
+
+    #[test]
+    fn nothing_rendered() -> anyhow::Result<()> {
+        let answers = Answers::from(HashMap::from([("port".to_owned(), "8080".to_owned())]));
+        let mut output = Vec::new();
+        let port = answers.scope(|| {
+            crate::written::<u16>("Port")
+                .key("port")
+                .prompt_with(std::io::empty(), &mut output)
+        })?;
+        assert_eq!(port, 8080);
+        assert!(output.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_answer() {
+        let answers = [("port", "foo")].into_iter().collect::<Answers>();
+        let res = answers.scope(|| {
+            crate::written::<u16>("Port")
+                .key("port")
+                .prompt_with("8080\n".as_bytes(), std::io::empty())
+        });
+        assert!(matches!(
+            res,
+            Err(e) if e.kind() == std::io::ErrorKind::InvalidData
+                && e.to_string() == "invalid answer for the key \"port\": invalid digit found in string"
+        ));
+    }
+
+    #[test]
+    fn missing_answer() -> anyhow::Result<()> {
+        let prompt = || crate::written::<u16>("Port").key("port");
+
+        let answers = Answers::new();
+        let port = answers.scope(|| prompt().prompt_with("80\n".as_bytes(), std::io::empty()))?;
+        assert_eq!(port, 80);
+
+        let answers = answers.on_missing(OnMissing::Error);
+        let res = answers.scope(|| prompt().prompt_with("80\n".as_bytes(), std::io::empty()));
+        assert!(matches!(res, Err(e) if e.kind() == std::io::ErrorKind::NotFound));
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_answers_installed() -> anyhow::Result<()> {
+        let port = crate::written::<u16>("Port")
+            .key("port")
+            .prompt_with("80\n".as_bytes(), std::io::empty())?;
+        assert_eq!(port, 80);
+
+        Ok(())
+    }
+
+    #[test]
+    fn nested_scopes() {
+        let outer = Answers::new().answer("a", "1");
+        let inner = Answers::new().answer("a", "2");
+        outer.scope(|| {
+            inner.scope(|| assert_eq!(super::resolve(None, "a").unwrap().as_deref(), Some("2")));
+            assert_eq!(super::resolve(None, "a").unwrap().as_deref(), Some("1"));
+        });
+        assert_eq!(super::resolve(None, "a").unwrap(), None);
+    }
+
+    #[test]
+    fn explicit_answers() -> anyhow::Result<()> {
+        let installed = Answers::new().answer("port", "80");
+        let explicit = Answers::new().answer("port", "8080");
+        let port = installed.scope(|| {
+            crate::written::<u16>("Port")
+                .key("port")
+                .answers(&explicit)
+                .prompt_with(std::io::empty(), std::io::empty())
+        })?;
+        assert_eq!(port, 8080);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_outside_scope() -> anyhow::Result<()> {
+        let answers = Answers::new().answer("port", "8080");
+
+        // The future is polled outside of the scope, so it doesn't see the installed answers.
+        let prompt = &mut crate::written::<u16>("Port").key("port");
+        let port = answers
+            .scope(move || prompt.prompt_with_async("80\n".as_bytes(), tokio::io::sink()))
+            .await?;
+        assert_eq!(port, 80);
+
+        let port = crate::written::<u16>("Port")
+            .key("port")
+            .answers(&answers)
+            .prompt_with_async(tokio::io::empty(), tokio::io::sink())
+            .await?;
+        assert_eq!(port, 8080);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn from_toml() -> anyhow::Result<()> {
+        let answers = Answers::from_toml(
+            "name = \"foo\"\nverbose = true\n[db]\nport = 5432\nhosts = [\"a\", \"b\"]\n",
+        )?;
+        assert_eq!(answers.get("name"), Some("foo"));
+        assert_eq!(answers.get("verbose"), Some("true"));
+        assert_eq!(answers.get("db.port"), Some("5432"));
+        assert_eq!(answers.get("db.hosts"), Some("a,b"));
+
+        assert!(Answers::from_toml("name = ").is_err());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn from_json() -> anyhow::Result<()> {
+        let answers = Answers::from_json(
+            r#"{"name": "foo", "verbose": false, "db": {"port": 5432, "user": null}}"#,
+        )?;
+        assert_eq!(answers.get("name"), Some("foo"));
+        assert_eq!(answers.get("verbose"), Some("false"));
+        assert_eq!(answers.get("db.port"), Some("5432"));
+        assert_eq!(answers.get("db.user"), None);
+
+        assert!(Answers::from_json("[1, 2]").is_err());
+
+        Ok(())
+    }
+}
@@ -51,9 +51,41 @@
 //!
 //! There is a similar case when [chaining promptables](crate::Promptable#prompt-format).
 //!
+//! # Styles
+//!
+//! The message, the input prefix, the list indices and the error messages can be styled with
+//! colors and text attributes, with the [`Style`] type:
+//!
+//! ```no_run
+//! # use ineed::prelude::*;
+//! use ineed::format::{Color, Style};
+//!
+//! let age = ineed::written::<u8>("Your age")
+//!   .fmt(ineed::fmt().msg_style(Style::new().bold().fg(Color::Blue)))
+//!   .prompt()
+//!   .unwrap();
+//! ```
+//!
+//! The styles are only applied when the prompt is displayed in a terminal, and when the
+//! [`NO_COLOR`](https://no-color.org) environment variable isn't set. This can be overridden with
+//! the [`styled`](FmtRule::styled) format rule.
+//!
+//! Format rules can also be built at runtime, with owned values, using the [`Theme`] type.
+//! A theme can be installed as the default format of every prompt, either for the whole
+//! application with [`set_default_theme`], or for the current thread with [`with_default_theme`].
+//!
 //! [1]: rules::WrittenFmtRules
 
+use std::borrow::Cow;
+
+mod global;
 pub mod rules;
+mod style;
+mod theme;
+
+pub use global::*;
+pub use style::*;
+pub use theme::*;
 
 /// The base type to customize a prompt styling.
 ///
@@ -110,11 +142,53 @@ pub trait FmtRule: Sized + Copy {
         MsgPrefix { rule: self, prefix }
     }
 
+    /// The message suffix, put right after the message and its default value.
+    fn msg_suffix(self, suffix: &str) -> MsgSuffix<'_, Self> {
+        MsgSuffix { rule: self, suffix }
+    }
+
+    /// The step prefix, put between the message prefix and the message to show the progress in a
+    /// chain of prompts (i.e. with [`Promptable::then`](crate::Promptable::then)).
+    ///
+    /// The template can contain these placeholders:
+    ///
+    /// * `{step}`: the position of the prompt in the chain, starting from 1.
+    /// * `{total}`: the amount of prompts in the chain.
+    /// * `{attempt}`: the attempt number of the prompt, starting from 1.
+    ///
+    /// A prompt outside of a chain is the step 1 of 1, and a confirmation (i.e. with
+    /// [`Promptable::confirm`](crate::Promptable::confirm)) shares the step of the answer it
+    /// confirms. By default, the step prefix is empty.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ineed::prelude::*;
+    /// let (name, age) = ineed::written::<String>("Your name")
+    ///   .then(ineed::written::<u8>("Your age"))
+    ///   .fmt(ineed::fmt().step_prefix("[{step}/{total}] "))
+    ///   .prompt()
+    ///   .unwrap();
+    /// ```
+    ///
+    /// This displays `- [1/2] Your name`, then `- [2/2] Your age`.
+    fn step_prefix(self, template: &str) -> StepPrefix<'_, Self> {
+        StepPrefix {
+            rule: self,
+            template,
+        }
+    }
+
     /// The input prefix, usually put right before the user input.
     fn input_prefix(self, prefix: &str) -> InputPrefix<'_, Self> {
         InputPrefix { rule: self, prefix }
     }
 
+    /// The error prefix, put right before the reason why an input was rejected.
+    fn err_prefix(self, prefix: &str) -> ErrPrefix<'_, Self> {
+        ErrPrefix { rule: self, prefix }
+    }
+
     /// Represents the surrounds of the index of each list item for selectable prompts.
     fn list_surrounds<'a>(self, open: &'a str, close: &'a str) -> ListSurrounds<'a, Self> {
         ListSurrounds {
@@ -123,11 +197,104 @@ pub trait FmtRule: Sized + Copy {
         }
     }
 
+    /// The surrounds of the default value, displayed right after the message when the prompt has
+    /// a default value (e.g. with [`Written::default`](crate::Written::default)).
+    fn default_surrounds<'a>(self, open: &'a str, close: &'a str) -> DefaultSurrounds<'a, Self> {
+        DefaultSurrounds {
+            rule: self,
+            surrounds: (open, close),
+        }
+    }
+
+    /// The label of the default value, put right after its opening surround (e.g. `default: `).
+    ///
+    /// It isn't displayed for default values that speak for themselves, such as the `Y/n` hint of
+    /// the [`bool`](crate::bool()) promptable.
+    fn default_label(self, label: &str) -> DefaultLabel<'_, Self> {
+        DefaultLabel { rule: self, label }
+    }
+
     /// The position of the message for selectable prompts (either below or above the list).
     fn list_msg_pos(self, pos: Position) -> ListMsgPos<Self> {
         ListMsgPos { rule: self, pos }
     }
 
+    /// The amount of items displayed at once for selectable prompts.
+    ///
+    /// When the list contains more items, it is split into pages. The user can then enter `n` or
+    /// `p` to display the next or the previous page. A size of 0 displays all the items at once,
+    /// which is the default.
+    fn page_size(self, size: usize) -> PageSize<Self> {
+        PageSize { rule: self, size }
+    }
+
+    /// The page indicator template, displayed below the list of selectable prompts when it's split
+    /// into pages (see the [`page_size`](FmtRule::page_size) format rule).
+    ///
+    /// The template can contain these placeholders:
+    ///
+    /// * `{page}`: the number of the displayed page, starting from 1.
+    /// * `{pages}`: the amount of pages.
+    /// * `{keys}`: the keys to navigate between the pages, e.g. `n: next, p: previous`.
+    ///
+    /// The indicator has the style of the list indices (see the
+    /// [`index_style`](FmtRule::index_style) format rule). By default, the template is
+    /// `page {page}/{pages} ({keys})`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ineed::prelude::*;
+    /// let letter = ineed::selected("Letter", [("a", 'a'), ("b", 'b'), ("c", 'c')])
+    ///   .fmt(ineed::fmt().page_size(2).page_indicator("-- {page}/{pages} --"))
+    ///   .prompt()
+    ///   .unwrap();
+    /// ```
+    fn page_indicator(self, template: &str) -> PageIndicator<'_, Self> {
+        PageIndicator {
+            rule: self,
+            template,
+        }
+    }
+
+    /// The style of the message, along with its prefix.
+    ///
+    /// See the [`styled`](FmtRule::styled) format rule to know when the styles are applied.
+    fn msg_style(self, style: Style) -> MsgStyle<Self> {
+        MsgStyle { rule: self, style }
+    }
+
+    /// The style of the input prefix.
+    ///
+    /// See the [`styled`](FmtRule::styled) format rule to know when the styles are applied.
+    fn input_style(self, style: Style) -> InputStyle<Self> {
+        InputStyle { rule: self, style }
+    }
+
+    /// The style of the index of each list item for selectable prompts, along with its surrounds.
+    ///
+    /// See the [`styled`](FmtRule::styled) format rule to know when the styles are applied.
+    fn index_style(self, style: Style) -> IndexStyle<Self> {
+        IndexStyle { rule: self, style }
+    }
+
+    /// The style of the error message, along with its prefix, displayed when an input is rejected.
+    ///
+    /// See the [`styled`](FmtRule::styled) format rule to know when the styles are applied.
+    fn err_style(self, style: Style) -> ErrStyle<Self> {
+        ErrStyle { rule: self, style }
+    }
+
+    /// Whether to apply the style rules or not.
+    ///
+    /// By default, the styles are only applied when prompting with the standard output (e.g.
+    /// with [`Promptable::prompt`](crate::Promptable::prompt)), if it's a terminal and if the
+    /// [`NO_COLOR`](https://no-color.org) environment variable isn't set. Otherwise, e.g. when
+    /// prompting with a custom writer, the text is displayed without any style.
+    fn styled(self, value: bool) -> Styled<Self> {
+        Styled { rule: self, value }
+    }
+
     /// Whether to break a line right after the message or not.
     fn break_line(self, value: bool) -> BreakLine<Self> {
         BreakLine { rule: self, value }
@@ -140,6 +307,28 @@ pub trait FmtRule: Sized + Copy {
     fn repeat_prompt(self, value: bool) -> RepeatPrompt<Self> {
         RepeatPrompt { rule: self, value }
     }
+
+    /// The inline layout, that displays the message and the user input on the same line.
+    ///
+    /// This is a preset of format rules: the message prefix and the input prefix are empty, the
+    /// message suffix is `: `, the line isn't broken after the message, and the message is
+    /// repeated when the input is invalid. The format rules chained after this one take precedence
+    /// over the preset.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ineed::prelude::*;
+    /// let name = ineed::written::<String>("Name")
+    ///   .fmt(ineed::fmt().inline())
+    ///   .prompt()
+    ///   .unwrap();
+    /// ```
+    ///
+    /// This displays `Name: ` then the user input, on the same line.
+    fn inline(self) -> Inline<Self> {
+        Inline { rule: self }
+    }
 }
 
 /// The message prefix format rule, usually put right before the message.
@@ -153,6 +342,28 @@ pub struct MsgPrefix<'a, R> {
 
 impl<R: FmtRule> FmtRule for MsgPrefix<'_, R> {}
 
+/// The message suffix format rule, put right after the message and its default value.
+///
+/// This is returned by [`FmtRule::msg_suffix`].
+#[derive(Clone, Copy)]
+pub struct MsgSuffix<'a, R> {
+    pub(crate) rule: R,
+    pub(crate) suffix: &'a str,
+}
+
+impl<R: FmtRule> FmtRule for MsgSuffix<'_, R> {}
+
+/// The step prefix format rule, put between the message prefix and the message.
+///
+/// This is returned by [`FmtRule::step_prefix`].
+#[derive(Clone, Copy)]
+pub struct StepPrefix<'a, R> {
+    pub(crate) rule: R,
+    pub(crate) template: &'a str,
+}
+
+impl<R: FmtRule> FmtRule for StepPrefix<'_, R> {}
+
 /// The input prefix format rule, usually put right before the user input.
 ///
 /// This is returned by [`FmtRule::input_prefix`].
@@ -164,6 +375,17 @@ pub struct InputPrefix<'a, R> {
 
 impl<R: FmtRule> FmtRule for InputPrefix<'_, R> {}
 
+/// The error prefix format rule, put right before the reason why an input was rejected.
+///
+/// This is returned by [`FmtRule::err_prefix`].
+#[derive(Clone, Copy)]
+pub struct ErrPrefix<'a, R> {
+    pub(crate) rule: R,
+    pub(crate) prefix: &'a str,
+}
+
+impl<R: FmtRule> FmtRule for ErrPrefix<'_, R> {}
+
 /// The format rule of the surrounds of the index of each list item for selectable prompts.
 ///
 /// This is returned by [`FmtRule::list_surrounds`].
@@ -175,8 +397,32 @@ pub struct ListSurrounds<'a, R> {
 
 impl<R: FmtRule> FmtRule for ListSurrounds<'_, R> {}
 
+/// The format rule of the surrounds of the default value of a prompt.
+///
+/// This is returned by [`FmtRule::default_surrounds`].
+#[derive(Clone, Copy)]
+pub struct DefaultSurrounds<'a, R> {
+    pub(crate) rule: R,
+    pub(crate) surrounds: (&'a str, &'a str),
+}
+
+impl<R: FmtRule> FmtRule for DefaultSurrounds<'_, R> {}
+
+/// The format rule of the label of the default value of a prompt.
+///
+/// This is returned by [`FmtRule::default_label`].
+#[derive(Clone, Copy)]
+pub struct DefaultLabel<'a, R> {
+    pub(crate) rule: R,
+    pub(crate) label: &'a str,
+}
+
+impl<R: FmtRule> FmtRule for DefaultLabel<'_, R> {}
+
 /// The position of the message, e.g. for selectable prompts.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Position {
     /// The message is displayed on the top (e.g. above the list for selectable prompts).
     Top,
@@ -195,6 +441,83 @@ pub struct ListMsgPos<R> {
 
 impl<R: FmtRule> FmtRule for ListMsgPos<R> {}
 
+/// The format rule of the amount of items displayed at once for selectable prompts.
+///
+/// This is returned by [`FmtRule::page_size`].
+#[derive(Clone, Copy)]
+pub struct PageSize<R> {
+    pub(crate) rule: R,
+    pub(crate) size: usize,
+}
+
+impl<R: FmtRule> FmtRule for PageSize<R> {}
+
+/// The format rule of the page indicator template for selectable prompts.
+///
+/// This is returned by [`FmtRule::page_indicator`].
+#[derive(Clone, Copy)]
+pub struct PageIndicator<'a, R> {
+    pub(crate) rule: R,
+    pub(crate) template: &'a str,
+}
+
+impl<R: FmtRule> FmtRule for PageIndicator<'_, R> {}
+
+/// The format rule of the style of the message.
+///
+/// This is returned by [`FmtRule::msg_style`].
+#[derive(Clone, Copy)]
+pub struct MsgStyle<R> {
+    pub(crate) rule: R,
+    pub(crate) style: Style,
+}
+
+impl<R: FmtRule> FmtRule for MsgStyle<R> {}
+
+/// The format rule of the style of the input prefix.
+///
+/// This is returned by [`FmtRule::input_style`].
+#[derive(Clone, Copy)]
+pub struct InputStyle<R> {
+    pub(crate) rule: R,
+    pub(crate) style: Style,
+}
+
+impl<R: FmtRule> FmtRule for InputStyle<R> {}
+
+/// The format rule of the style of the index of each list item for selectable prompts.
+///
+/// This is returned by [`FmtRule::index_style`].
+#[derive(Clone, Copy)]
+pub struct IndexStyle<R> {
+    pub(crate) rule: R,
+    pub(crate) style: Style,
+}
+
+impl<R: FmtRule> FmtRule for IndexStyle<R> {}
+
+/// The format rule of the style of the error message.
+///
+/// This is returned by [`FmtRule::err_style`].
+#[derive(Clone, Copy)]
+pub struct ErrStyle<R> {
+    pub(crate) rule: R,
+    pub(crate) style: Style,
+}
+
+impl<R: FmtRule> FmtRule for ErrStyle<R> {}
+
+/// The format rule to whether apply the style rules or not.
+///
+/// This is returned by [`FmtRule::styled`].
+#[derive(Clone, Copy)]
+pub struct Styled<R> {
+    pub(crate) rule: R,
+    pub(crate) value: bool,
+}
+
+impl<R: FmtRule> FmtRule for Styled<R> {}
+
 /// The format rule to whether break a line or not right after the message.
 ///
 /// This is returned by [`FmtRule::break_line`].
@@ -217,13 +540,106 @@ pub struct RepeatPrompt<R> {
 
 impl<R: FmtRule> FmtRule for RepeatPrompt<R> {}
 
+/// The inline layout preset, that displays the message and the user input on the same line.
+///
+/// This is returned by [`FmtRule::inline`].
+#[derive(Clone, Copy)]
+pub struct Inline<R> {
+    pub(crate) rule: R,
+}
+
+impl<R: FmtRule> FmtRule for Inline<R> {}
+
+/// Represents a set of rules that can format the reason why an input was rejected.
+///
+/// This is implemented for set of rules, represented by the [`FmtRules`] trait, and is used to
+/// build a [`Rejection`](crate::Rejection).
+pub trait ErrFmt {
+    /// Returns the message displayed to the user when their input is rejected for the given reason.
+    fn fmt_err(&self, reason: &str) -> String;
+}
+
+/// Represents a set of rules that knows the progress of its prompts, i.e. their step in a chain,
+/// and their attempt number.
+///
+/// This is implemented for set of rules, represented by the [`FmtRules`] trait, and is used by
+/// the [`step_prefix`](FmtRule::step_prefix) format rule.
+pub trait Progress {
+    /// Returns the amount of prompts that use this set of rules, e.g. 2 for a chain of 2 prompts.
+    fn steps(&self) -> usize;
+
+    /// Returns whether the prompts already know their step.
+    fn is_numbered(&self) -> bool;
+
+    /// Returns this set of rules, with the first prompt at the given step, out of `total` steps.
+    fn numbered(&self, step: usize, total: usize) -> Self;
+
+    /// Returns this set of rules, with the given attempt number.
+    fn with_attempt(&self, attempt: usize) -> Self;
+
+    /// Returns this set of rules numbered from the first step, unless its prompts already know
+    /// their step.
+    ///
+    /// This is used by the chains, so only the outermost one numbers the prompts.
+    fn root(&self) -> Cow<'_, Self>
+    where
+        Self: Clone,
+    {
+        if self.is_numbered() {
+            Cow::Borrowed(self)
+        } else {
+            Cow::Owned(self.numbered(1, self.steps()))
+        }
+    }
+}
+
+/// Replaces the placeholders of the step prefix template with their values.
+pub(crate) fn fill_step_prefix(
+    template: &str, (step, total): (usize, usize), attempt: usize,
+) -> String {
+    template
+        .replace("{step}", &step.to_string())
+        .replace("{total}", &total.to_string())
+        .replace("{attempt}", &attempt.to_string())
+}
+
+/// Replaces the placeholders of the page indicator template with their values.
+pub(crate) fn fill_page_indicator(template: &str, page: usize, pages: usize, keys: &str) -> String {
+    template
+        .replace("{page}", &page.to_string())
+        .replace("{pages}", &pages.to_string())
+        .replace("{keys}", keys)
+}
+
 /// Types representing set of rules supported by promptables.
 ///
 /// This is used as a bound for the [`Promptable::FmtRules`](crate::Promptable::FmtRules)
 /// associated type.
 #[cfg_attr(nightly, doc(notable_trait))]
-pub trait FmtRules: From<Fmt> + Mergeable + Partial + Default {}
-impl<T> FmtRules for T where T: From<Fmt> + Mergeable + Partial + Default {}
+pub trait FmtRules:
+    From<Fmt>
+    + From<Styled<Fmt>>
+    + From<&'static Theme>
+    + Mergeable
+    + Partial
+    + ErrFmt
+    + Progress
+    + Default
+    + Clone
+{
+}
+impl<T> FmtRules for T where
+    T: From<Fmt>
+        + From<Styled<Fmt>>
+        + From<&'static Theme>
+        + Mergeable
+        + Partial
+        + ErrFmt
+        + Progress
+        + Default
+        + Clone
+{
+}
 
 #[cfg(test)]
 mod tests {
@@ -281,6 +697,7 @@ mod tests {
                 input_prefix: "my giga input prefix",
                 break_line: !default_fmt_rules.break_line,
                 repeat_prompt: !default_fmt_rules.repeat_prompt,
+                ..default_fmt_rules
             }
         )
     }
@@ -358,6 +775,30 @@ mod tests {
         )
     }
 
+    #[test]
+    fn styles_disabled_by_default() {
+        use crate::format::{Color, ErrFmt as _, Style};
+
+        let style = Style::new().fg(Color::Red);
+        let fmt_rules = SelectedFmtRules::from(crate::fmt().err_style(style).index_style(style));
+        assert_eq!(fmt_rules.expand(), ExpandedSelectedFmtRules::default());
+
+        let fmt_rules = SelectedFmtRules::from(crate::fmt().err_style(style));
+        let styled = SelectedFmtRules::from(crate::fmt().styled(true));
+        assert_eq!(
+            fmt_rules.merge_with(&styled).expand(),
+            ExpandedSelectedFmtRules {
+                err_style: style,
+                ..Default::default()
+            }
+        );
+        assert_eq!(fmt_rules.fmt_err("foo"), "! foo");
+        assert_eq!(
+            fmt_rules.merge_with(&styled).fmt_err("foo"),
+            "\x1b[31m! foo\x1b[0m"
+        );
+    }
+
     #[test]
     fn selected_fmt_conflicting_merge() {
         let fmt_rules1 = crate::fmt().list_surrounds("<", ">");
@@ -0,0 +1,163 @@
+use std::{cell::Cell, sync::OnceLock};
+
+use super::{FmtRule as _, FmtRules, Theme, stdout_styled};
+
+static DEFAULT_THEME: OnceLock<Theme> = OnceLock::new();
+
+thread_local! {
+    static SCOPED_THEME: Cell<Option<&'static Theme>> = const { Cell::new(None) };
+}
+
+/// Installs the default theme of the application.
+///
+/// Every prompt then starts from this theme instead of the default format rules, so you don't
+/// have to repeat the same [`Promptable::fmt`](crate::Promptable::fmt) call on each prompt.
+/// The format rules provided to a prompt still take precedence over the default theme.
+///
+/// The default theme can only be installed once, usually at the start of the application.
+///
+/// # Errors
+///
+/// If a default theme was already installed, the provided theme is returned back, boxed.
+///
+/// # Example
+///
+/// ```no_run
+/// # use ineed::prelude::*;
+/// use ineed::format::Theme;
+///
+/// ineed::format::set_default_theme(Theme::fancy()).unwrap();
+///
+/// // Both prompts use the fancy theme, but the second one with its own input prefix.
+/// let name = ineed::written::<String>("Your name").prompt().unwrap();
+/// let age = ineed::written::<u8>("Your age")
+///   .fmt(ineed::fmt().input_prefix(">> "))
+///   .prompt()
+///   .unwrap();
+/// ```
+pub fn set_default_theme(theme: Theme) -> Result<(), Box<Theme>> {
+    DEFAULT_THEME.set(theme).map_err(Box::new)
+}
+
+/// Runs the provided function with a default theme for the current thread.
+///
+/// During the call, the prompts of the current thread start from this theme. Its rules take
+/// precedence over the theme installed with [`set_default_theme`], but not over the format rules
+/// provided to the prompts themselves. The previous scoped theme is restored when the function
+/// returns, even if it panics. For asynchronous prompts, the scoped theme is read when the
+/// prompt method is called, not when the returned future is polled.
+///
+/// The theme must live for the whole program, e.g. in a `static` item, or with [`Box::leak`].
+///
+/// # Example
+///
+/// ```no_run
+/// # use ineed::prelude::*;
+/// use std::sync::LazyLock;
+/// use ineed::format::Theme;
+///
+/// static WIZARD: LazyLock<Theme> = LazyLock::new(Theme::minimal);
+///
+/// let (name, age) = ineed::format::with_default_theme(&WIZARD, || {
+///   let name = ineed::written::<String>("Your name").prompt().unwrap();
+///   let age = ineed::written::<u8>("Your age").prompt().unwrap();
+///   (name, age)
+/// });
+/// ```
+pub fn with_default_theme<T, F>(theme: &'static Theme, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    struct Restore(Option<&'static Theme>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SCOPED_THEME.set(self.0);
+        }
+    }
+
+    let _restore = Restore(SCOPED_THEME.replace(Some(theme)));
+    f()
+}
+
+/// Returns the format rules every prompt starts from, i.e. the scoped theme merged with the
+/// default theme.
+pub(crate) fn default_rules<F: FmtRules>() -> F {
+    let rules = DEFAULT_THEME
+        .get()
+        .map_or_else(|| F::from(super::fmt()), F::from);
+    match SCOPED_THEME.get() {
+        Some(theme) => F::from(theme).merge_with(&rules),
+        None => rules,
+    }
+}
+
+/// Returns the format rules used when prompting to the standard output.
+///
+/// The [`styled`](super::FmtRule::styled) rule is set if the standard output is a terminal,
+/// unless the default themes specify it.
+pub(crate) fn stdout_rules<F: FmtRules>() -> F {
+    default_rules::<F>().merge_with(&F::from(super::fmt().styled(stdout_styled())))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::LazyLock;
+
+    use super::{Theme, with_default_theme};
+    use crate::prelude::*;
+
+    static MINIMAL: LazyLock<Theme> = LazyLock::new(Theme::minimal);
+    static FANCY: LazyLock<Theme> = LazyLock::new(Theme::fancy);
+
+    #[test]
+    fn scoped_theme() -> anyhow::Result<()> {
+        let mut output = Vec::new();
+        let res = with_default_theme(&MINIMAL, || {
+            crate::written::<u8>("Your age").prompt_with("foo\n42\n".as_bytes(), &mut output)
+        })?;
+        assert_eq!(res, 42);
+        assert_eq!(
+            String::from_utf8(output)?,
+            "Your age: invalid digit found in string\nYour age: "
+        );
+
+        let mut output = Vec::new();
+        crate::written::<u8>("Your age").prompt_with("42\n".as_bytes(), &mut output)?;
+        assert_eq!(String::from_utf8(output)?, "- Your age\n> ");
+
+        Ok(())
+    }
+
+    #[test]
+    fn closest_rule_wins() -> anyhow::Result<()> {
+        let mut output = Vec::new();
+        let (name, license) = with_default_theme(&FANCY, || {
+            crate::written::<String>("Name")
+                .fmt(crate::fmt().input_prefix(">> "))
+                .then(crate::selected("License", [("MIT", 1)]))
+                .prompt_with("foo\n1\n".as_bytes(), &mut output)
+        })?;
+        assert_eq!((name.as_str(), license), ("foo", 1));
+        assert_eq!(
+            String::from_utf8(output)?,
+            "? Name\n>> ? License\n  1) MIT\n› "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn nested_scopes() -> anyhow::Result<()> {
+        let mut output = Vec::new();
+        with_default_theme(&FANCY, || {
+            with_default_theme(&MINIMAL, || {
+                crate::written::<u8>("Age").prompt_with("42\n".as_bytes(), &mut output)
+            })?;
+            crate::written::<u8>("Age").prompt_with("42\n".as_bytes(), &mut output)
+        })?;
+        assert_eq!(String::from_utf8(output)?, "Age: ? Age\n› ");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use crate::format::{ErrFmt, FmtRule, Mergeable, Partial, Progress};
+
+use super::ExpandedThenFmtRules;
+
+/// The set of rules accepted by confirmed prompts (i.e. with
+/// [`Promptable::confirm`](crate::Promptable::confirm)).
+///
+/// Unlike with [`ThenFmtRules`](super::ThenFmtRules), the confirmation isn't a step of its own:
+/// it's displayed with the step of the last prompt it confirms.
+///
+/// See the [module documentation](crate::format) for more information.
+#[derive(Default, Clone, Copy)]
+pub struct ConfirmFmtRules<A, B> {
+    /// The rules of the confirmed prompt.
+    pub a_rules: A,
+    /// The rules of the confirmation question.
+    pub b_rules: B,
+}
+
+impl<A, B, R> From<R> for ConfirmFmtRules<A, B>
+where
+    A: From<R>,
+    B: From<R>,
+    R: FmtRule,
+{
+    fn from(value: R) -> Self {
+        Self {
+            a_rules: A::from(value),
+            b_rules: B::from(value),
+        }
+    }
+}
+
+impl<A, B> Mergeable for ConfirmFmtRules<A, B>
+where
+    A: Mergeable,
+    B: Mergeable,
+{
+    fn merge_with(&self, other: &Self) -> Self {
+        Self {
+            a_rules: self.a_rules.merge_with(&other.a_rules),
+            b_rules: self.b_rules.merge_with(&other.b_rules),
+        }
+    }
+}
+
+/// The steps of a confirmed prompt are the steps of the prompt it confirms. The confirmation
+/// shares the last of them.
+impl<A, B> Progress for ConfirmFmtRules<A, B>
+where
+    A: Progress,
+    B: Progress,
+{
+    fn steps(&self) -> usize {
+        self.a_rules.steps()
+    }
+
+    fn is_numbered(&self) -> bool {
+        self.a_rules.is_numbered()
+    }
+
+    fn numbered(&self, step: usize, total: usize) -> Self {
+        let last = step + self.a_rules.steps().saturating_sub(1);
+        Self {
+            a_rules: self.a_rules.numbered(step, total),
+            b_rules: self.b_rules.numbered(last, total),
+        }
+    }
+
+    fn with_attempt(&self, attempt: usize) -> Self {
+        Self {
+            a_rules: self.a_rules.with_attempt(attempt),
+            b_rules: self.b_rules.with_attempt(attempt),
+        }
+    }
+}
+
+/// The rejections of a confirmed prompt are formatted with the rules of the prompt it confirms,
+/// as the confirmation handles its own rejections.
+impl<A, B> ErrFmt for ConfirmFmtRules<A, B>
+where
+    A: ErrFmt,
+{
+    fn fmt_err(&self, reason: &str) -> String {
+        self.a_rules.fmt_err(reason)
+    }
+}
+
+impl<A, B> Partial for ConfirmFmtRules<A, B>
+where
+    A: Partial,
+    B: Partial,
+{
+    type Expanded = ExpandedThenFmtRules<<A as Partial>::Expanded, <B as Partial>::Expanded>;
+    fn expand(&self) -> Self::Expanded {
+        ExpandedThenFmtRules {
+            a_rules: self.a_rules.expand(),
+            b_rules: self.b_rules.expand(),
+        }
+    }
+}
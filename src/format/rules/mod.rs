@@ -1,9 +1,11 @@
 //! Module exposing various types of rules, discriminated by prompt kinds.
 
+mod confirm;
 mod selected;
 mod then;
 mod written;
 
+pub use confirm::*;
 pub use selected::*;
 pub use then::*;
 pub use written::*;
@@ -1,21 +1,37 @@
 use crate::format::{
-    BreakLine, ConstDefault, Fmt, InputPrefix, ListMsgPos, ListSurrounds, Mergeable, MsgPrefix,
-    Partial, Position, RepeatPrompt,
+    BreakLine, ConstDefault, DefaultLabel, DefaultSurrounds, ErrFmt, ErrPrefix, ErrStyle, Fmt,
+    IndexStyle, Inline, InputPrefix, InputStyle, ListMsgPos, ListSurrounds, Mergeable, MsgPrefix,
+    MsgStyle, MsgSuffix, PageIndicator, PageSize, Partial, Position, Progress, RepeatPrompt,
+    StepPrefix, Style, Styled, Theme,
 };
 
-use super::ExpandedWrittenFmtRules;
+use super::{ExpandedWrittenFmtRules, WrittenFmtRules};
 
 /// The set of rules accepted by selectable prompts (e.g. [`selected`](crate::selected)).
 ///
 /// See the [module documentation](crate::format) for more information.
-#[derive(Default)]
+#[derive(Default, Clone, Copy)]
 pub struct SelectedFmtRules<'a> {
-    msg_prefix: Option<&'a str>,
-    input_prefix: Option<&'a str>,
-    repeat_prompt: Option<bool>,
-    break_line: Option<bool>,
-    list_surrounds: Option<(&'a str, &'a str)>,
-    list_msg_pos: Option<Position>,
+    pub(super) msg_prefix: Option<&'a str>,
+    pub(super) msg_suffix: Option<&'a str>,
+    pub(super) step_prefix: Option<&'a str>,
+    pub(super) input_prefix: Option<&'a str>,
+    pub(super) err_prefix: Option<&'a str>,
+    pub(super) default_surrounds: Option<(&'a str, &'a str)>,
+    pub(super) default_label: Option<&'a str>,
+    pub(super) repeat_prompt: Option<bool>,
+    pub(super) break_line: Option<bool>,
+    pub(super) list_surrounds: Option<(&'a str, &'a str)>,
+    pub(super) list_msg_pos: Option<Position>,
+    pub(super) page_size: Option<usize>,
+    pub(super) page_indicator: Option<&'a str>,
+    pub(super) msg_style: Option<Style>,
+    pub(super) input_style: Option<Style>,
+    pub(super) index_style: Option<Style>,
+    pub(super) err_style: Option<Style>,
+    pub(super) styled: Option<bool>,
+    pub(super) step: Option<(usize, usize)>,
+    pub(super) attempt: Option<usize>,
 }
 
 impl From<Fmt> for SelectedFmtRules<'_> {
@@ -36,6 +52,30 @@ where
     }
 }
 
+impl<'a, R> From<MsgSuffix<'a, R>> for SelectedFmtRules<'a>
+where
+    Self: From<R>,
+{
+    fn from(value: MsgSuffix<'a, R>) -> Self {
+        Self {
+            msg_suffix: Some(value.suffix),
+            ..Self::from(value.rule)
+        }
+    }
+}
+
+impl<'a, R> From<StepPrefix<'a, R>> for SelectedFmtRules<'a>
+where
+    Self: From<R>,
+{
+    fn from(value: StepPrefix<'a, R>) -> Self {
+        Self {
+            step_prefix: Some(value.template),
+            ..Self::from(value.rule)
+        }
+    }
+}
+
 impl<'a, R> From<InputPrefix<'a, R>> for SelectedFmtRules<'a>
 where
     Self: From<R>,
@@ -48,6 +88,42 @@ where
     }
 }
 
+impl<'a, R> From<ErrPrefix<'a, R>> for SelectedFmtRules<'a>
+where
+    Self: From<R>,
+{
+    fn from(value: ErrPrefix<'a, R>) -> Self {
+        Self {
+            err_prefix: Some(value.prefix),
+            ..Self::from(value.rule)
+        }
+    }
+}
+
+impl<'a, R> From<DefaultLabel<'a, R>> for SelectedFmtRules<'a>
+where
+    Self: From<R>,
+{
+    fn from(value: DefaultLabel<'a, R>) -> Self {
+        Self {
+            default_label: Some(value.label),
+            ..Self::from(value.rule)
+        }
+    }
+}
+
+impl<'a, R> From<DefaultSurrounds<'a, R>> for SelectedFmtRules<'a>
+where
+    Self: From<R>,
+{
+    fn from(value: DefaultSurrounds<'a, R>) -> Self {
+        Self {
+            default_surrounds: Some(value.surrounds),
+            ..Self::from(value.rule)
+        }
+    }
+}
+
 impl<R> From<BreakLine<R>> for SelectedFmtRules<'_>
 where
     Self: From<R>,
@@ -60,6 +136,22 @@ where
     }
 }
 
+impl<R> From<Inline<R>> for SelectedFmtRules<'_>
+where
+    Self: From<R>,
+{
+    fn from(value: Inline<R>) -> Self {
+        Self {
+            msg_prefix: Some(""),
+            msg_suffix: Some(": "),
+            input_prefix: Some(""),
+            break_line: Some(false),
+            repeat_prompt: Some(true),
+            ..Self::from(value.rule)
+        }
+    }
+}
+
 impl<R> From<RepeatPrompt<R>> for SelectedFmtRules<'_>
 where
     Self: From<R>,
@@ -96,30 +188,228 @@ where
     }
 }
 
+impl<R> From<PageSize<R>> for SelectedFmtRules<'_>
+where
+    Self: From<R>,
+{
+    fn from(value: PageSize<R>) -> Self {
+        Self {
+            page_size: Some(value.size),
+            ..Self::from(value.rule)
+        }
+    }
+}
+
+impl<'a, R> From<PageIndicator<'a, R>> for SelectedFmtRules<'a>
+where
+    Self: From<R>,
+{
+    fn from(value: PageIndicator<'a, R>) -> Self {
+        Self {
+            page_indicator: Some(value.template),
+            ..Self::from(value.rule)
+        }
+    }
+}
+
+impl<R> From<MsgStyle<R>> for SelectedFmtRules<'_>
+where
+    Self: From<R>,
+{
+    fn from(value: MsgStyle<R>) -> Self {
+        Self {
+            msg_style: Some(value.style),
+            ..Self::from(value.rule)
+        }
+    }
+}
+
+impl<R> From<InputStyle<R>> for SelectedFmtRules<'_>
+where
+    Self: From<R>,
+{
+    fn from(value: InputStyle<R>) -> Self {
+        Self {
+            input_style: Some(value.style),
+            ..Self::from(value.rule)
+        }
+    }
+}
+
+impl<R> From<IndexStyle<R>> for SelectedFmtRules<'_>
+where
+    Self: From<R>,
+{
+    fn from(value: IndexStyle<R>) -> Self {
+        Self {
+            index_style: Some(value.style),
+            ..Self::from(value.rule)
+        }
+    }
+}
+
+impl<R> From<ErrStyle<R>> for SelectedFmtRules<'_>
+where
+    Self: From<R>,
+{
+    fn from(value: ErrStyle<R>) -> Self {
+        Self {
+            err_style: Some(value.style),
+            ..Self::from(value.rule)
+        }
+    }
+}
+
+impl<R> From<Styled<R>> for SelectedFmtRules<'_>
+where
+    Self: From<R>,
+{
+    fn from(value: Styled<R>) -> Self {
+        Self {
+            styled: Some(value.value),
+            ..Self::from(value.rule)
+        }
+    }
+}
+
+/// The rules of a written prompt also apply to the selectable prompts it leads to, e.g. a selectable
+/// prompt given to [`or_else`](crate::Promptable::or_else).
+impl<'a> From<WrittenFmtRules<'a>> for SelectedFmtRules<'a> {
+    fn from(rules: WrittenFmtRules<'a>) -> Self {
+        Self {
+            msg_prefix: rules.msg_prefix,
+            msg_suffix: rules.msg_suffix,
+            step_prefix: rules.step_prefix,
+            input_prefix: rules.input_prefix,
+            err_prefix: rules.err_prefix,
+            default_surrounds: rules.default_surrounds,
+            default_label: rules.default_label,
+            repeat_prompt: rules.repeat_prompt,
+            break_line: rules.break_line,
+            msg_style: rules.msg_style,
+            input_style: rules.input_style,
+            err_style: rules.err_style,
+            styled: rules.styled,
+            step: rules.step,
+            attempt: rules.attempt,
+            ..Self::default()
+        }
+    }
+}
+
+impl<'a, 't: 'a> From<&'t Theme> for SelectedFmtRules<'a> {
+    fn from(theme: &'t Theme) -> Self {
+        Self {
+            msg_prefix: theme.msg_prefix.as_deref(),
+            msg_suffix: theme.msg_suffix.as_deref(),
+            step_prefix: theme.step_prefix.as_deref(),
+            input_prefix: theme.input_prefix.as_deref(),
+            err_prefix: theme.err_prefix.as_deref(),
+            default_surrounds: Theme::surrounds(&theme.default_surrounds),
+            default_label: theme.default_label.as_deref(),
+            repeat_prompt: theme.repeat_prompt,
+            break_line: theme.break_line,
+            list_surrounds: Theme::surrounds(&theme.list_surrounds),
+            list_msg_pos: theme.list_msg_pos,
+            page_size: theme.page_size,
+            page_indicator: theme.page_indicator.as_deref(),
+            msg_style: theme.msg_style,
+            input_style: theme.input_style,
+            index_style: theme.index_style,
+            err_style: theme.err_style,
+            styled: theme.styled,
+            step: None,
+            attempt: None,
+        }
+    }
+}
+
 impl Mergeable for SelectedFmtRules<'_> {
     fn merge_with(&self, other: &Self) -> Self {
         Self {
             msg_prefix: self.msg_prefix.or(other.msg_prefix),
+            msg_suffix: self.msg_suffix.or(other.msg_suffix),
+            step_prefix: self.step_prefix.or(other.step_prefix),
             input_prefix: self.input_prefix.or(other.input_prefix),
+            err_prefix: self.err_prefix.or(other.err_prefix),
+            default_surrounds: self.default_surrounds.or(other.default_surrounds),
+            default_label: self.default_label.or(other.default_label),
             break_line: self.break_line.or(other.break_line),
             repeat_prompt: self.repeat_prompt.or(other.repeat_prompt),
             list_surrounds: self.list_surrounds.or(other.list_surrounds),
             list_msg_pos: self.list_msg_pos.or(other.list_msg_pos),
+            page_size: self.page_size.or(other.page_size),
+            page_indicator: self.page_indicator.or(other.page_indicator),
+            msg_style: self.msg_style.or(other.msg_style),
+            input_style: self.input_style.or(other.input_style),
+            index_style: self.index_style.or(other.index_style),
+            err_style: self.err_style.or(other.err_style),
+            styled: self.styled.or(other.styled),
+            step: self.step.or(other.step),
+            attempt: self.attempt.or(other.attempt),
+        }
+    }
+}
+
+impl Progress for SelectedFmtRules<'_> {
+    fn steps(&self) -> usize {
+        1
+    }
+
+    fn is_numbered(&self) -> bool {
+        self.step.is_some()
+    }
+
+    fn numbered(&self, step: usize, total: usize) -> Self {
+        Self {
+            step: Some((step, total)),
+            ..*self
+        }
+    }
+
+    fn with_attempt(&self, attempt: usize) -> Self {
+        Self {
+            attempt: Some(attempt),
+            ..*self
         }
     }
 }
 
+impl ErrFmt for SelectedFmtRules<'_> {
+    fn fmt_err(&self, reason: &str) -> String {
+        self.expand().fmt_err(reason)
+    }
+}
+
 impl<'a> Partial for SelectedFmtRules<'a> {
     type Expanded = ExpandedSelectedFmtRules<'a>;
 
     fn expand(&self) -> Self::Expanded {
+        let styled = self.styled.unwrap_or(false);
         Self::Expanded {
             msg_prefix: self
                 .msg_prefix
                 .unwrap_or(Self::Expanded::DEFAULT.msg_prefix),
+            msg_suffix: self
+                .msg_suffix
+                .unwrap_or(Self::Expanded::DEFAULT.msg_suffix),
+            step_prefix: self
+                .step_prefix
+                .unwrap_or(Self::Expanded::DEFAULT.step_prefix),
+            step: self.step.unwrap_or(Self::Expanded::DEFAULT.step),
+            attempt: self.attempt.unwrap_or(Self::Expanded::DEFAULT.attempt),
             input_prefix: self
                 .input_prefix
                 .unwrap_or(Self::Expanded::DEFAULT.input_prefix),
+            err_prefix: self
+                .err_prefix
+                .unwrap_or(Self::Expanded::DEFAULT.err_prefix),
+            default_surrounds: self
+                .default_surrounds
+                .unwrap_or(Self::Expanded::DEFAULT.default_surrounds),
+            default_label: self
+                .default_label
+                .unwrap_or(Self::Expanded::DEFAULT.default_label),
             break_line: self
                 .break_line
                 .unwrap_or(Self::Expanded::DEFAULT.break_line),
@@ -132,6 +422,26 @@ impl<'a> Partial for SelectedFmtRules<'a> {
             list_msg_pos: self
                 .list_msg_pos
                 .unwrap_or(Self::Expanded::DEFAULT.list_msg_pos),
+            page_size: self.page_size.unwrap_or(Self::Expanded::DEFAULT.page_size),
+            page_indicator: self
+                .page_indicator
+                .unwrap_or(Self::Expanded::DEFAULT.page_indicator),
+            msg_style: styled
+                .then_some(self.msg_style)
+                .flatten()
+                .unwrap_or(Self::Expanded::DEFAULT.msg_style),
+            input_style: styled
+                .then_some(self.input_style)
+                .flatten()
+                .unwrap_or(Self::Expanded::DEFAULT.input_style),
+            index_style: styled
+                .then_some(self.index_style)
+                .flatten()
+                .unwrap_or(Self::Expanded::DEFAULT.index_style),
+            err_style: styled
+                .then_some(self.err_style)
+                .flatten()
+                .unwrap_or(Self::Expanded::DEFAULT.err_style),
         }
     }
 }
@@ -141,8 +451,22 @@ impl<'a> Partial for SelectedFmtRules<'a> {
 pub struct ExpandedSelectedFmtRules<'a> {
     /// The message prefix, that is put right before the message.
     pub msg_prefix: &'a str,
+    /// The message suffix, that is put right after the message and its default value.
+    pub msg_suffix: &'a str,
+    /// The step prefix template, that is put between the message prefix and the message.
+    pub step_prefix: &'a str,
+    /// The step of the prompt and the total amount of steps, used by the step prefix.
+    pub step: (usize, usize),
+    /// The attempt number of the prompt, used by the step prefix.
+    pub attempt: usize,
     /// The input prefix, that is put right before the user input.
     pub input_prefix: &'a str,
+    /// The error prefix, that is put right before the reason why an input was rejected.
+    pub err_prefix: &'a str,
+    /// The surrounds of the default value, displayed right after the message.
+    pub default_surrounds: (&'a str, &'a str),
+    /// The label of the default value, put right after its opening surround.
+    pub default_label: &'a str,
     /// Whether to break the line after the message or not.
     pub break_line: bool,
     /// Whether to repeat the message, along with its prefix and the input prefix,
@@ -152,16 +476,49 @@ pub struct ExpandedSelectedFmtRules<'a> {
     pub list_surrounds: (&'a str, &'a str),
     /// The position of the message.
     pub list_msg_pos: Position,
+    /// The amount of items displayed at once, or 0 to display all the items.
+    pub page_size: usize,
+    /// The page indicator template, displayed below the list when it's split into pages.
+    pub page_indicator: &'a str,
+    /// The style of the message, along with its prefix.
+    pub msg_style: Style,
+    /// The style of the input prefix.
+    pub input_style: Style,
+    /// The style of each list item index, along with its surrounds.
+    pub index_style: Style,
+    /// The style of the error message, along with its prefix.
+    pub err_style: Style,
+}
+
+impl ErrFmt for ExpandedSelectedFmtRules<'_> {
+    fn fmt_err(&self, reason: &str) -> String {
+        self.err_style
+            .paint(format_args!("{}{reason}", self.err_prefix))
+            .to_string()
+    }
 }
 
 impl ConstDefault for ExpandedSelectedFmtRules<'_> {
     const DEFAULT: Self = Self {
         msg_prefix: ExpandedWrittenFmtRules::DEFAULT.msg_prefix,
+        msg_suffix: ExpandedWrittenFmtRules::DEFAULT.msg_suffix,
+        step_prefix: ExpandedWrittenFmtRules::DEFAULT.step_prefix,
+        step: ExpandedWrittenFmtRules::DEFAULT.step,
+        attempt: ExpandedWrittenFmtRules::DEFAULT.attempt,
         input_prefix: ExpandedWrittenFmtRules::DEFAULT.input_prefix,
+        err_prefix: ExpandedWrittenFmtRules::DEFAULT.err_prefix,
+        default_surrounds: ExpandedWrittenFmtRules::DEFAULT.default_surrounds,
+        default_label: ExpandedWrittenFmtRules::DEFAULT.default_label,
         break_line: ExpandedWrittenFmtRules::DEFAULT.break_line,
         repeat_prompt: ExpandedWrittenFmtRules::DEFAULT.repeat_prompt,
         list_surrounds: ("[", "] - "),
         list_msg_pos: Position::Bottom,
+        page_size: 0,
+        page_indicator: "page {page}/{pages} ({keys})",
+        msg_style: ExpandedWrittenFmtRules::DEFAULT.msg_style,
+        input_style: ExpandedWrittenFmtRules::DEFAULT.input_style,
+        index_style: Style::new(),
+        err_style: ExpandedWrittenFmtRules::DEFAULT.err_style,
     };
 }
 
@@ -1,10 +1,12 @@
-use crate::format::{ConstDefault, FmtRule, Mergeable, Partial};
+use crate::format::{ConstDefault, ErrFmt, FmtRule, Mergeable, Partial, Progress};
+
+use super::SelectedFmtRules;
 
 /// The set of rules accepted by chained prompts (i.e. with
 /// [`Promptable::then`](crate::Promptable::then)).
 ///
 /// See the [module documentation](crate::format) for more information.
-#[derive(Default)]
+#[derive(Default, Clone, Copy)]
 pub struct ThenFmtRules<A, B> {
     /// The rules of the first prompt.
     pub a_rules: A,
@@ -26,6 +28,21 @@ where
     }
 }
 
+/// The rules of a selectable prompt apply to both prompts of the chain it leads to, e.g. for the
+/// payload prompts of the [`variants`](crate::variants()) promptable.
+impl<'a, A, B> From<SelectedFmtRules<'a>> for ThenFmtRules<A, B>
+where
+    A: From<SelectedFmtRules<'a>>,
+    B: From<SelectedFmtRules<'a>>,
+{
+    fn from(value: SelectedFmtRules<'a>) -> Self {
+        Self {
+            a_rules: A::from(value),
+            b_rules: B::from(value),
+        }
+    }
+}
+
 impl<A, B> Mergeable for ThenFmtRules<A, B>
 where
     A: Mergeable,
@@ -39,7 +56,48 @@ where
     }
 }
 
-/// The expanded version of [`ThenFmtRules`].
+/// The steps of a chain are the steps of its first prompt, followed by the steps of its second
+/// prompt.
+impl<A, B> Progress for ThenFmtRules<A, B>
+where
+    A: Progress,
+    B: Progress,
+{
+    fn steps(&self) -> usize {
+        self.a_rules.steps() + self.b_rules.steps()
+    }
+
+    fn is_numbered(&self) -> bool {
+        self.a_rules.is_numbered()
+    }
+
+    fn numbered(&self, step: usize, total: usize) -> Self {
+        Self {
+            a_rules: self.a_rules.numbered(step, total),
+            b_rules: self.b_rules.numbered(step + self.a_rules.steps(), total),
+        }
+    }
+
+    fn with_attempt(&self, attempt: usize) -> Self {
+        Self {
+            a_rules: self.a_rules.with_attempt(attempt),
+            b_rules: self.b_rules.with_attempt(attempt),
+        }
+    }
+}
+
+/// The rejections of a chain are formatted with the rules of its first prompt, as the second
+/// prompt handles its own rejections.
+impl<A, B> ErrFmt for ThenFmtRules<A, B>
+where
+    A: ErrFmt,
+{
+    fn fmt_err(&self, reason: &str) -> String {
+        self.a_rules.fmt_err(reason)
+    }
+}
+
+/// The expanded version of [`ThenFmtRules`] and [`ConfirmFmtRules`](super::ConfirmFmtRules).
 pub struct ExpandedThenFmtRules<A, B> {
     /// The expanded version of the first prompt set of rules.
     pub a_rules: A,
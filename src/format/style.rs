@@ -0,0 +1,263 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    io::{self, IsTerminal as _},
+};
+
+/// A color of the terminal, used by a [`Style`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Color {
+    /// The black color.
+    Black,
+    /// The red color.
+    Red,
+    /// The green color.
+    Green,
+    /// The yellow color.
+    Yellow,
+    /// The blue color.
+    Blue,
+    /// The magenta color.
+    Magenta,
+    /// The cyan color.
+    Cyan,
+    /// The white color.
+    White,
+    /// The bright version of the black color, usually displayed as gray.
+    BrightBlack,
+    /// The bright version of the red color.
+    BrightRed,
+    /// The bright version of the green color.
+    BrightGreen,
+    /// The bright version of the yellow color.
+    BrightYellow,
+    /// The bright version of the blue color.
+    BrightBlue,
+    /// The bright version of the magenta color.
+    BrightMagenta,
+    /// The bright version of the cyan color.
+    BrightCyan,
+    /// The bright version of the white color.
+    BrightWhite,
+    /// A color of the 256-color palette of the terminal.
+    Fixed(u8),
+    /// A true color, with its red, green and blue components.
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// Writes the parameters of the SGR sequence for this color.
+    ///
+    /// The `base` is 30 for a foreground color, and 40 for a background color.
+    fn write_sgr(self, f: &mut fmt::Formatter<'_>, base: u8) -> fmt::Result {
+        let code = match self {
+            Self::Black => 0,
+            Self::Red => 1,
+            Self::Green => 2,
+            Self::Yellow => 3,
+            Self::Blue => 4,
+            Self::Magenta => 5,
+            Self::Cyan => 6,
+            Self::White => 7,
+            Self::BrightBlack => 60,
+            Self::BrightRed => 61,
+            Self::BrightGreen => 62,
+            Self::BrightYellow => 63,
+            Self::BrightBlue => 64,
+            Self::BrightMagenta => 65,
+            Self::BrightCyan => 66,
+            Self::BrightWhite => 67,
+            Self::Fixed(i) => return write!(f, "{};5;{i}", base + 8),
+            Self::Rgb(r, g, b) => return write!(f, "{};2;{r};{g};{b}", base + 8),
+        };
+        write!(f, "{}", base + code)
+    }
+}
+
+/// The style of a text displayed in the terminal, with ANSI escape sequences.
+///
+/// This is used by the style format rules, e.g. [`FmtRule::msg_style`](super::FmtRule::msg_style).
+///
+/// # Example
+///
+/// ```no_run
+/// # use ineed::prelude::*;
+/// use ineed::format::{Color, Style};
+///
+/// let age = ineed::written::<u8>("Your age")
+///   .fmt(
+///     ineed::fmt()
+///       .msg_style(Style::new().bold())
+///       .err_style(Style::new().fg(Color::Red))
+///   )
+///   .prompt()
+///   .unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct Style {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    bold: bool,
+    dim: bool,
+    underline: bool,
+}
+
+impl Style {
+    /// Returns a plain style, that doesn't change the text.
+    pub const fn new() -> Self {
+        Self {
+            fg: None,
+            bg: None,
+            bold: false,
+            dim: false,
+            underline: false,
+        }
+    }
+
+    /// Sets the foreground color, i.e. the color of the text.
+    pub const fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    /// Sets the background color.
+    pub const fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Displays the text in bold.
+    pub const fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Displays the text with a lower intensity.
+    pub const fn dim(mut self) -> Self {
+        self.dim = true;
+        self
+    }
+
+    /// Underlines the text.
+    pub const fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Returns whether the style doesn't change the text.
+    pub fn is_plain(&self) -> bool {
+        *self == Self::new()
+    }
+
+    /// Returns the value wrapped with the escape sequences of this style.
+    ///
+    /// If the style is plain, the value is displayed as is.
+    pub fn paint<T: Display>(self, value: T) -> Painted<T> {
+        Painted { style: self, value }
+    }
+
+    fn write_sgr(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut params = Vec::new();
+        if self.bold {
+            params.push(1);
+        }
+        if self.dim {
+            params.push(2);
+        }
+        if self.underline {
+            params.push(4);
+        }
+
+        f.write_str("\x1b[")?;
+        for (i, param) in params.iter().enumerate() {
+            if i > 0 {
+                f.write_str(";")?;
+            }
+            write!(f, "{param}")?;
+        }
+        for (color, base) in [(self.fg, 30), (self.bg, 40)] {
+            if let Some(color) = color {
+                if !params.is_empty() {
+                    f.write_str(";")?;
+                }
+                color.write_sgr(f, base)?;
+                params.push(base);
+            }
+        }
+        f.write_str("m")
+    }
+}
+
+/// A value displayed with a style.
+///
+/// This is returned by the [`Style::paint`] method.
+#[derive(Debug, Clone, Copy)]
+pub struct Painted<T> {
+    style: Style,
+    value: T,
+}
+
+impl<T: Display> Display for Painted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.style.is_plain() {
+            return self.value.fmt(f);
+        }
+
+        self.style.write_sgr(f)?;
+        self.value.fmt(f)?;
+        f.write_str("\x1b[0m")
+    }
+}
+
+/// Returns whether the styles should be applied to the standard output.
+///
+/// This is the case if the standard output is a terminal, and if the `NO_COLOR` environment
+/// variable isn't set to a non-empty value.
+pub(crate) fn stdout_styled() -> bool {
+    io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Color, Style};
+
+    #[test]
+    fn plain_style() {
+        assert!(Style::new().is_plain());
+        assert_eq!(Style::new().paint("foo").to_string(), "foo");
+    }
+
+    #[test]
+    fn sgr_sequences() {
+        assert_eq!(
+            Style::new().bold().paint("foo").to_string(),
+            "\x1b[1mfoo\x1b[0m"
+        );
+        assert_eq!(
+            Style::new().fg(Color::Red).paint("foo").to_string(),
+            "\x1b[31mfoo\x1b[0m"
+        );
+        assert_eq!(
+            Style::new()
+                .dim()
+                .underline()
+                .fg(Color::BrightBlue)
+                .bg(Color::White)
+                .paint("foo")
+                .to_string(),
+            "\x1b[2;4;94;47mfoo\x1b[0m"
+        );
+        assert_eq!(
+            Style::new()
+                .fg(Color::Fixed(208))
+                .bg(Color::Rgb(1, 2, 3))
+                .paint("foo")
+                .to_string(),
+            "\x1b[38;5;208;48;2;1;2;3mfoo\x1b[0m"
+        );
+    }
+}
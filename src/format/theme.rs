@@ -0,0 +1,242 @@
+use super::{Color, FmtRule, Position, Style};
+
+/// A set of format rules, built at runtime.
+///
+/// Unlike the format rules built with the [`fmt()`](super::fmt) function, a theme owns its
+/// values. So it can be stored in the state of your application, or loaded from a configuration
+/// file with the `serde` feature. Each rule is optional: an omitted rule takes its default value,
+/// or the value of an outer format, like the other format rules.
+///
+/// A theme is applied to any promptable by reference, with the
+/// [`Promptable::fmt`](crate::Promptable::fmt) method. As it implements [`FmtRule`], it can also
+/// be chained with other format rules, which take precedence over the theme.
+///
+/// # Example
+///
+/// ```no_run
+/// # use ineed::prelude::*;
+/// use ineed::format::Theme;
+///
+/// let theme = Theme {
+///   input_prefix: Some(">> ".to_owned()),
+///   ..Theme::colorful()
+/// };
+///
+/// let name = ineed::written::<String>("Your name")
+///   .fmt(&theme)
+///   .prompt()
+///   .unwrap();
+/// let license = ineed::selected("License", [("MIT", 1), ("GPL", 2)])
+///   .fmt((&theme).list_surrounds("(", ") "))
+///   .prompt()
+///   .unwrap();
+/// ```
+///
+/// With the `serde` feature, a theme can be deserialized, e.g. from this TOML file:
+///
+/// ```toml
+/// msg_prefix = "? "
+/// break_line = false
+/// list_surrounds = ["  ", ") "]
+/// err_style = { fg = "red", bold = true }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct Theme {
+    /// The message prefix (see [`FmtRule::msg_prefix`]).
+    pub msg_prefix: Option<String>,
+    /// The message suffix (see [`FmtRule::msg_suffix`]).
+    pub msg_suffix: Option<String>,
+    /// The step prefix template (see [`FmtRule::step_prefix`]).
+    pub step_prefix: Option<String>,
+    /// The input prefix (see [`FmtRule::input_prefix`]).
+    pub input_prefix: Option<String>,
+    /// The error prefix (see [`FmtRule::err_prefix`]).
+    pub err_prefix: Option<String>,
+    /// The surrounds of the default value (see [`FmtRule::default_surrounds`]).
+    pub default_surrounds: Option<(String, String)>,
+    /// The label of the default value (see [`FmtRule::default_label`]).
+    pub default_label: Option<String>,
+    /// The surrounds of each list item index (see [`FmtRule::list_surrounds`]).
+    pub list_surrounds: Option<(String, String)>,
+    /// The position of the message for selectable prompts (see [`FmtRule::list_msg_pos`]).
+    pub list_msg_pos: Option<Position>,
+    /// The amount of items displayed at once (see [`FmtRule::page_size`]).
+    pub page_size: Option<usize>,
+    /// The page indicator template (see [`FmtRule::page_indicator`]).
+    pub page_indicator: Option<String>,
+    /// Whether to break a line after the message (see [`FmtRule::break_line`]).
+    pub break_line: Option<bool>,
+    /// Whether to repeat the message (see [`FmtRule::repeat_prompt`]).
+    pub repeat_prompt: Option<bool>,
+    /// The style of the message (see [`FmtRule::msg_style`]).
+    pub msg_style: Option<Style>,
+    /// The style of the input prefix (see [`FmtRule::input_style`]).
+    pub input_style: Option<Style>,
+    /// The style of each list item index (see [`FmtRule::index_style`]).
+    pub index_style: Option<Style>,
+    /// The style of the error messages (see [`FmtRule::err_style`]).
+    pub err_style: Option<Style>,
+    /// Whether to apply the styles (see [`FmtRule::styled`]).
+    pub styled: Option<bool>,
+}
+
+impl Theme {
+    /// A theme that displays the message and the input on the same line, without prefixes.
+    ///
+    /// ```txt
+    /// Your age: foo
+    /// invalid digit found in string
+    /// Your age: 42
+    /// ```
+    pub fn minimal() -> Self {
+        Self {
+            msg_prefix: Some(String::new()),
+            input_prefix: Some(": ".to_owned()),
+            err_prefix: Some(String::new()),
+            list_surrounds: Some((String::new(), ". ".to_owned())),
+            break_line: Some(false),
+            repeat_prompt: Some(true),
+            ..Self::default()
+        }
+    }
+
+    /// A theme with colored messages, prefixes and errors.
+    ///
+    /// The styles are only applied in a terminal, see [`FmtRule::styled`].
+    pub fn colorful() -> Self {
+        Self {
+            msg_style: Some(Style::new().bold().fg(Color::Cyan)),
+            input_style: Some(Style::new().fg(Color::Green)),
+            index_style: Some(Style::new().fg(Color::Yellow)),
+            err_style: Some(Style::new().fg(Color::Red)),
+            ..Self::default()
+        }
+    }
+
+    /// A theme with Unicode symbols as prefixes.
+    ///
+    /// ```txt
+    /// ? Your age
+    /// › foo
+    /// ✗ invalid digit found in string
+    /// › 42
+    /// ```
+    pub fn fancy() -> Self {
+        Self {
+            msg_prefix: Some("? ".to_owned()),
+            input_prefix: Some("› ".to_owned()),
+            err_prefix: Some("✗ ".to_owned()),
+            default_surrounds: Some((" (".to_owned(), ")".to_owned())),
+            list_surrounds: Some(("  ".to_owned(), ") ".to_owned())),
+            list_msg_pos: Some(Position::Top),
+            msg_style: Some(Style::new().bold()),
+            index_style: Some(Style::new().dim()),
+            err_style: Some(Style::new().fg(Color::Red)),
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn surrounds(surrounds: &Option<(String, String)>) -> Option<(&str, &str)> {
+        surrounds
+            .as_ref()
+            .map(|(open, close)| (open.as_str(), close.as_str()))
+    }
+}
+
+impl FmtRule for &Theme {}
+
+#[cfg(test)]
+mod tests {
+    use super::Theme;
+    use crate::prelude::*;
+
+    #[test]
+    fn apply_theme() -> anyhow::Result<()> {
+        let theme = Theme::minimal();
+        let mut output = Vec::new();
+        let res = crate::written::<u8>("Your age")
+            .fmt(&theme)
+            .prompt_with("foo\n42\n".as_bytes(), &mut output)?;
+        assert_eq!(res, 42);
+        assert_eq!(
+            String::from_utf8(output)?,
+            "Your age: invalid digit found in string\nYour age: "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn override_theme() -> anyhow::Result<()> {
+        let theme = Theme {
+            input_prefix: Some(">> ".to_owned()),
+            ..Theme::fancy()
+        };
+        let mut output = Vec::new();
+        let res = crate::selected("License", [("MIT", 1), ("GPL", 2)])
+            .fmt((&theme).list_surrounds("(", ") "))
+            .fmt(crate::fmt().msg_prefix("- "))
+            .prompt_with("2\n".as_bytes(), &mut output)?;
+        assert_eq!(res, 2);
+        assert_eq!(
+            String::from_utf8(output)?,
+            "? License\n(1) MIT\n(2) GPL\n>> "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn theme_with_then() -> anyhow::Result<()> {
+        let theme = Theme::fancy();
+        let mut output = Vec::new();
+        let (name, license) = crate::written::<String>("Name")
+            .then(crate::selected("License", [("MIT", 1)]))
+            .fmt(&theme)
+            .prompt_with("foo\n1\n".as_bytes(), &mut output)?;
+        assert_eq!((name.as_str(), license), ("foo", 1));
+        assert_eq!(
+            String::from_utf8(output)?,
+            "? Name\n› ? License\n  1) MIT\n› "
+        );
+
+        Ok(())
+    }
+
+    #[cfg(all(feature = "serde", feature = "json"))]
+    #[test]
+    fn deserialize_theme() -> anyhow::Result<()> {
+        use crate::format::{Color, Position, Style};
+
+        let theme: Theme = serde_json::from_str(
+            r#"{
+                "msg_prefix": "? ",
+                "list_surrounds": ["<", "> "],
+                "list_msg_pos": "top",
+                "err_style": { "fg": "bright_red", "bold": true },
+                "index_style": { "fg": { "rgb": [1, 2, 3] } }
+            }"#,
+        )?;
+        assert_eq!(
+            theme,
+            Theme {
+                msg_prefix: Some("? ".to_owned()),
+                list_surrounds: Some(("<".to_owned(), "> ".to_owned())),
+                list_msg_pos: Some(Position::Top),
+                err_style: Some(Style::new().bold().fg(Color::BrightRed)),
+                index_style: Some(Style::new().fg(Color::Rgb(1, 2, 3))),
+                ..Theme::default()
+            }
+        );
+
+        let res = serde_json::from_str::<Theme>(r#"{ "msg_prefx": "? " }"#);
+        assert!(res.is_err());
+
+        let json = serde_json::to_string(&Theme::colorful())?;
+        assert_eq!(serde_json::from_str::<Theme>(&json)?, Theme::colorful());
+
+        Ok(())
+    }
+}
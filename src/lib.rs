@@ -17,7 +17,8 @@
 //! >
 //! ```
 //!
-//! If the user enters an invalid input, the prompt is repeated.
+//! If the user enters an invalid input, the reason why it was rejected is printed, and the prompt
+//! is repeated.
 //!
 //! You can customize the prompt's [mod@format]:
 //!
@@ -65,21 +66,123 @@
 #![cfg_attr(nightly, feature(doc_cfg, doc_notable_trait))]
 #![warn(missing_docs, unused_allocation, missing_copy_implementations)]
 
+// Allows the derive macros to refer to this crate with `::ineed` paths inside of it.
+extern crate self as ineed;
+
 use std::{
+    env,
+    fmt::{self as std_fmt, Display},
     io::{self, BufRead, Write},
     marker::PhantomData,
     ops::ControlFlow,
 };
 
-use self::format::FmtRules;
+use self::format::{ErrFmt, FmtRules, Progress as _};
 
+pub mod answers;
 pub mod format;
 pub use format::fmt;
 
 mod promptables;
-use format::rules::WrittenFmtRules;
+#[cfg(feature = "crossterm")]
+#[cfg_attr(nightly, doc(cfg(feature = "crossterm")))]
+pub mod raw;
+#[cfg(feature = "testing")]
+#[cfg_attr(nightly, doc(cfg(feature = "testing")))]
+pub mod testing;
+use format::rules::{SelectedFmtRules, WrittenFmtRules};
 pub use promptables::*;
 
+/// Derives the [`FromPrompt`] trait for a struct.
+///
+/// Each field of the struct is prompted one after another, in the order of declaration, and the
+/// chain outputs the struct directly. Fields of type `bool` are prompted with a [boolean](bool())
+/// prompt, and other fields with a [written] prompt, unless the `selected` attribute is provided.
+///
+/// The message of each prompt is the name of the field (e.g. `Max connections` for a field named
+/// `max_connections`), unless specified otherwise.
+///
+/// # Attributes
+///
+/// The prompt of each field can be customized with the `#[ineed(...)]` attribute:
+///
+/// - `msg = "..."`: the message of the prompt. This is required for tuple structs.
+/// - `fmt = expr`: the custom [format](Promptable::fmt) of the prompt.
+/// - `default = expr`: the default value of the prompt, used when the user enters nothing.
+/// - `selected = expr`: prompts the field with a [selected] prompt with the provided list,
+///   instead of a written prompt.
+/// - `until = expr`: the [filter](Promptable::until) of the input.
+/// - `until_msg = "..."`: the message displayed when the filter rejects the input.
+/// - `key = "..."`: the [key](Promptable::key) of the prompt, to take its answer from the
+///   installed [answers].
+/// - `max_tries = expr`: the [maximum amount of tries](Promptable::max_tries). It requires the
+///   `max_tries_fallback` attribute.
+/// - `max_tries_fallback = expr`: the value of the field once the maximum amount of tries is
+///   exceeded. It's evaluated only in this case.
+///
+/// The `fmt = expr` attribute can also be put on the struct itself, to customize the format of
+/// the whole chain.
+///
+/// # Example
+///
+/// ```no_run
+/// # use ineed::prelude::*;
+/// #[derive(ineed::Promptable)]
+/// #[ineed(fmt = ineed::fmt().input_prefix(">> "))]
+/// struct Config {
+///   #[ineed(msg = "Your name")]
+///   name: String,
+///   #[ineed(default = 8080, until = |port| *port > 1024, until_msg = "use a non-privileged port")]
+///   port: u16,
+///   #[ineed(max_tries = 3, max_tries_fallback = false)]
+///   verbose: bool,
+/// }
+///
+/// let config = Config::prompt().unwrap();
+/// ```
+#[cfg(feature = "derive")]
+#[cfg_attr(nightly, doc(cfg(feature = "derive")))]
+pub use ineed_derive::Promptable;
+
+/// Derives the [`Selectable`] trait for an enum.
+///
+/// The user is asked to choose a variant of the enum in a [selected] prompt. The label of each
+/// variant is its name, unless specified otherwise.
+///
+/// If a variant has fields, they are prompted right after it is selected, in the same way as the
+/// fields of a struct with the [`Promptable`](derive@Promptable) derive macro, and with the same
+/// attributes. The message of unnamed fields is the label of the variant by default. In this case,
+/// the list is built with the [`variants()`] function.
+///
+/// # Attributes
+///
+/// Each variant can be customized with the `#[ineed(...)]` attribute:
+///
+/// - `label = "..."`: the label of the variant in the list.
+/// - `default`: makes the variant the default choice, when the user enters nothing.
+///
+/// The way the labels are matched against the user input can be customized on the enum itself,
+/// with the `label_match = expr` attribute (see [`Selected::label_match`]).
+///
+/// # Example
+///
+/// ```no_run
+/// # use ineed::prelude::*;
+/// #[derive(ineed::Selectable)]
+/// enum Level {
+///   Good,
+///   #[ineed(label = "So-so")]
+///   Medium,
+///   Bad,
+///   Custom(#[ineed(msg = "Your custom level")] u8),
+/// }
+///
+/// let level = Level::prompt_select("Your level").prompt().unwrap();
+/// ```
+#[cfg(feature = "derive")]
+#[cfg_attr(nightly, doc(cfg(feature = "derive")))]
+pub use ineed_derive::Selectable;
+
 /// Exposes some traits to access their methods more conveniently.
 ///
 /// This is intended to be used like this: `use ineed::prelude::*;`.
@@ -87,7 +190,69 @@ pub use promptables::*;
 /// Promptable types aren't exposed here, so you must either import them yourself or use path syntax,
 /// for example with `ineed::written(...)`.
 pub mod prelude {
-    pub use super::{Promptable as _, format::FmtRule as _};
+    #[cfg(feature = "async")]
+    pub use super::AsyncPromptable as _;
+    #[cfg(feature = "crossterm")]
+    pub use super::RawPromptable as _;
+    pub use super::{FromPrompt as _, Promptable as _, Selectable as _, format::FmtRule as _};
+}
+
+/// The reason why an input was rejected by a promptable.
+///
+/// This is returned by [`Promptable::prompt_once`] when the input is invalid. It contains the
+/// reason of the rejection, and the message displayed to the user before the prompt is repeated,
+/// which is formatted with the format rules of the promptable (e.g. with the
+/// [`err_prefix`](format::FmtRule::err_prefix) rule).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rejection {
+    reason: String,
+    msg: String,
+}
+
+impl Rejection {
+    /// Creates a new rejection with the provided reason, formatted with the given format rules.
+    pub fn new<R, F>(reason: R, fmt: &F) -> Self
+    where
+        R: Display,
+        F: ErrFmt + ?Sized,
+    {
+        let reason = reason.to_string();
+        Self {
+            msg: fmt.fmt_err(&reason),
+            reason,
+        }
+    }
+
+    /// Returns the reason of the rejection.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// Returns the message displayed to the user, i.e. the formatted reason.
+    pub fn msg(&self) -> &str {
+        &self.msg
+    }
+
+    /// Displays the rejection message to the user.
+    pub(crate) fn write_to<W: Write>(&self, mut write: W) -> io::Result<()> {
+        writeln!(write, "{}", self.msg)
+    }
+
+    /// Displays the rejection message to the user, asynchronously.
+    #[cfg(feature = "async")]
+    pub(crate) async fn write_to_async<W>(&self, write: &mut W) -> io::Result<()>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        use tokio::io::AsyncWriteExt as _;
+        write.write_all(format!("{}\n", self.msg).as_bytes()).await
+    }
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std_fmt::Formatter<'_>) -> std_fmt::Result {
+        f.write_str(&self.reason)
+    }
 }
 
 /// Represents types that can be prompted to the console.
@@ -101,37 +266,53 @@ pub trait Promptable {
     /// Prompts the user for an input.
     ///
     /// It returns [`ControlFlow::Break`] with the final value if the input is correct, or
-    /// [`ControlFlow::Continue`] otherwise.
+    /// [`ControlFlow::Continue`] with the reason of the [`Rejection`] otherwise. The rejection
+    /// message isn't printed by this method: it is the responsibility of the caller, before
+    /// prompting again.
     ///
     /// This method mutates the promptable, which means that the printed messages between the prompts
     /// might not be the same, and the state of the promptable itself. So you can't reuse a promptable
     /// after you used it once, and expect it to have the same behavior.
     ///
     /// This method takes some arguments for IO streams, and the prompt format.
+    ///
+    /// # Errors
+    ///
+    /// Any IO error is returned as is. If the input stream reached its end before the user could
+    /// enter anything (e.g. the standard input was closed, or the user pressed Ctrl-D), an error of
+    /// kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) is returned.
     fn prompt_once<R, W>(
         &mut self, read: R, write: W, fmt: &Self::FmtRules,
-    ) -> io::Result<ControlFlow<Self::Output>>
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
     where
         R: BufRead,
         W: Write;
 
     /// Prompts the user for an input until it's valid.
-    fn prompt_with<R, W>(&mut self, mut read: R, mut write: W) -> io::Result<Self::Output>
+    ///
+    /// Each time the input is rejected, the reason of the rejection is printed before prompting
+    /// again.
+    ///
+    /// # Errors
+    ///
+    /// The prompt stops as soon as an error is returned by [`prompt_once`](Promptable::prompt_once),
+    /// which means that it doesn't loop forever when the end of the input stream is reached.
+    /// In such case, an error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) is returned.
+    fn prompt_with<R, W>(&mut self, read: R, write: W) -> io::Result<Self::Output>
     where
         R: BufRead,
         W: Write,
     {
-        let fmt = Self::FmtRules::from(fmt());
-        loop {
-            if let ControlFlow::Break(out) = self.prompt_once(&mut read, &mut write, &fmt)? {
-                return Ok(out);
-            }
-        }
+        prompt_with_fmt(self, read, write, &format::default_rules())
     }
 
     /// Prompts the user for an input until it's valid, using the standard input and output.
+    ///
+    /// The [style rules](format::FmtRule::styled) are applied if the standard output is
+    /// a terminal.
     fn prompt(&mut self) -> io::Result<Self::Output> {
-        self.prompt_with(io::stdin().lock(), io::stdout())
+        let fmt = format::stdout_rules();
+        prompt_with_fmt(self, io::stdin().lock(), io::stdout(), &fmt)
     }
 
     /// Limits the amount of tries for the prompt to succeed.
@@ -184,6 +365,9 @@ pub trait Promptable {
     /// used when prompting the latter is the one defined specifically for the latter, as it has
     /// more precedence over the format rules of the whole chain.
     ///
+    /// Each promptable of the chain knows its position, even in nested chains, so you can show the
+    /// progress of the chain with the [`step_prefix`](format::FmtRule::step_prefix) format rule.
+    ///
     /// # Examples
     ///
     /// The below code shows how to basically use this method:
@@ -241,16 +425,179 @@ pub trait Promptable {
         }
     }
 
+    /// Asks the user to confirm the answer, and restarts the prompt if they don't.
+    ///
+    /// After this promptable succeeds, a [`bool()`] question is asked with the provided message, in
+    /// which `{value}` is replaced by the answer. The answers accepted as yes or no are the same as
+    /// for the `bool` promptable. If the user answers no, this promptable is prompted again from
+    /// the state it had before the first answer, e.g. a selected item can be picked again.
+    ///
+    /// The confirmation isn't a step of its own: it's displayed with the step of the answer it
+    /// confirms. Its format rules are the second rules of the [`ConfirmFmtRules`] set.
+    ///
+    /// [`ConfirmFmtRules`]: format::rules::ConfirmFmtRules
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ineed::prelude::*;
+    /// let email = ineed::written::<String>("Your email")
+    ///   .confirm("Is {value} correct?")
+    ///   .prompt()
+    ///   .unwrap();
+    /// ```
+    ///
+    /// The above example will show something similar to this:
+    ///
+    /// ```txt
+    /// - Your email
+    /// > foo@bar.com
+    /// - Is foo@bar.com correct?
+    /// > yes
+    /// ```
+    fn confirm(self, msg: &str) -> Confirm<'_, Self>
+    where
+        Self: Sized + Clone,
+        Self::Output: Display,
+    {
+        Confirm {
+            initial: self.clone(),
+            prompt: self,
+            msg,
+        }
+    }
+
+    /// Chains this promptable with another one, which is built from the output of this one.
+    ///
+    /// Unlike [`then`](Promptable::then), the next promptable doesn't exist before this one
+    /// succeeds: it's returned by the given function, which takes the output of this promptable.
+    /// So the message, the choices or the validation of the next prompt can depend on the
+    /// previous answer. The output is flattened in the same way.
+    ///
+    /// The next promptable is built once, then prompted until it succeeds. Its format rules are
+    /// the second rules of the chain, like with the `then` method.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ineed::prelude::*;
+    /// let (country, city) = ineed::selected("Country", [("France", "FR"), ("Germany", "DE")])
+    ///   .and_then(|country| {
+    ///     let cities = match *country {
+    ///       "FR" => [("Paris", "Paris"), ("Lyon", "Lyon")],
+    ///       _ => [("Berlin", "Berlin"), ("Munich", "Munich")],
+    ///     };
+    ///     ineed::selected("City", cities)
+    ///   })
+    ///   .prompt()
+    ///   .unwrap();
+    /// ```
+    fn and_then<F, P, O>(self, then: F) -> AndThen<Self, F, P, O>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Output) -> P,
+        P: Promptable,
+        O: FromOutput<<AndThen<Self, F, P, O> as Flattenable>::RawOutput>,
+    {
+        AndThen {
+            first: self,
+            then,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Interprets the user input with another promptable, if this one rejects it.
+    ///
+    /// Only this promptable is displayed. When it rejects the input, the same line is given to
+    /// the other promptable, without rendering anything. The output tells which promptable
+    /// accepted the input, with the [`Either`] type. If both promptables reject the input, both
+    /// reasons are displayed, e.g. `! invalid digit found in string, or please answer with yes or
+    /// no`, and the user is prompted again.
+    ///
+    /// The other promptable is given the same format rules as this one, but it doesn't display
+    /// anything. This also means that it should only read a single line.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ineed::prelude::*;
+    /// let user = ineed::written::<u32>("User ID or name")
+    ///   .or_else(ineed::written::<String>(""))
+    ///   .prompt()
+    ///   .unwrap();
+    /// match user {
+    ///   ineed::Either::Left(id) => println!("User #{id}"),
+    ///   ineed::Either::Right(name) => println!("User {name}"),
+    /// }
+    /// ```
+    fn or_else<P>(self, other: P) -> OrElse<Self, P>
+    where
+        Self: Sized,
+        P: Promptable,
+        <P as Promptable>::FmtRules: From<Self::FmtRules>,
+    {
+        OrElse { first: self, other }
+    }
+
+    /// Asks the same question repeatedly, and collects the answers.
+    ///
+    /// The collection ends when the user enters an empty line, or the line set with the
+    /// [`Repeat::sentinel`] method. Each answer is entered on its own line, unlike with the
+    /// [`separated`] promptable, so it's convenient for long answers such as file paths. The
+    /// amount of answers can be bounded with the [`Repeat::min`] and [`Repeat::max`] methods.
+    /// If an answer is given without reading any input, e.g. with the [`key`](Promptable::key)
+    /// method, the collection ends right after it.
+    ///
+    /// The output can be any collection that implements [`FromIterator`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ineed::prelude::*;
+    /// let files: Vec<String> = ineed::written::<String>("Files to upload")
+    ///   .repeat()
+    ///   .min(1)
+    ///   .prompt()
+    ///   .unwrap();
+    /// ```
+    ///
+    /// The above example will show something similar to this:
+    ///
+    /// ```txt
+    /// - Files to upload
+    /// > foo.txt
+    /// > bar/baz.txt
+    /// >
+    /// ```
+    fn repeat<C>(self) -> Repeat<Self, C>
+    where
+        Self: Sized,
+        C: FromIterator<Self::Output>,
+    {
+        Repeat {
+            prompt: self,
+            items: Vec::new(),
+            sentinel: String::new(),
+            min: 0,
+            max: None,
+            _marker: PhantomData,
+        }
+    }
+
     /// Adds a filter to the user input, before validating it.
     ///
     /// The given function returns whether the value entered by the user is valid or not.
     ///
+    /// The message displayed when the value is rejected can be customized with the
+    /// [`Until::msg`] method.
+    ///
     /// # Example
     ///
     /// ```no_run
     /// # use ineed::prelude::*;
     /// let age = ineed::written::<u8>("You age")
     ///   .until(|age| *age > 3 && *age < 120)
+    ///   .msg("you must be between 4 and 119 years old")
     ///   .prompt()
     ///   .unwrap();
     /// ```
@@ -262,6 +609,7 @@ pub trait Promptable {
         Until {
             prompt: self,
             until,
+            msg: None,
         }
     }
 
@@ -284,6 +632,62 @@ pub trait Promptable {
         Map { prompt: self, map }
     }
 
+    /// Adds a validator to the user input, with a custom message for each rejection.
+    ///
+    /// The given function returns `Ok(())` if the value entered by the user is valid, or the
+    /// reason why it's rejected otherwise. The reason is then displayed with the format rules of
+    /// the prompt (e.g. with its [error prefix](format::FmtRule::err_prefix)), and the user is
+    /// prompted again.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ineed::prelude::*;
+    /// let port = ineed::written::<u16>("Port")
+    ///   .validate(|port| match port {
+    ///     ..1024 => Err(format!("{port} is a well-known port")),
+    ///     _ => Ok(()),
+    ///   })
+    ///   .prompt()
+    ///   .unwrap();
+    /// ```
+    fn validate<F, E>(self, validate: F) -> Validate<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Output) -> Result<(), E>,
+        E: Display,
+    {
+        Validate {
+            prompt: self,
+            validate,
+        }
+    }
+
+    /// Maps the user input into another value, or rejects it.
+    ///
+    /// The given function takes the value entered by the user, and returns either a new value of
+    /// any type from it, or the reason why it's rejected. Like with the
+    /// [`validate`](Promptable::validate) method, the reason is displayed with the format rules of
+    /// the prompt, and the user is prompted again.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ineed::prelude::*;
+    /// let level = ineed::written::<i32>("Your level")
+    ///   .try_map(u8::try_from)
+    ///   .prompt()
+    ///   .unwrap();
+    /// ```
+    fn try_map<F, T, E>(self, map: F) -> TryMap<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Output) -> Result<T, E>,
+        E: Display,
+    {
+        TryMap { prompt: self, map }
+    }
+
     /// Gives the promptable a custom format.
     ///
     /// The custom format must be compatible with the promptable type. This compatibility
@@ -314,4 +718,501 @@ pub trait Promptable {
             rules: fmt.into(),
         }
     }
+
+    /// Gives the promptable a stable key, to take its answer from the installed
+    /// [`Answers`](answers::Answers) instead of prompting the user.
+    ///
+    /// If no answers are installed, or if the answer of the key is missing, the user is prompted
+    /// as usual. See the [`answers`] module for more information.
+    ///
+    /// The answer goes through the wrappers of the keyed promptable only, so you should give the
+    /// key after the [`until`](Promptable::until) filters for them to check the answer as well.
+    /// Otherwise, an answer rejected by an outer filter would be replayed endlessly.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ineed::prelude::*;
+    /// let answers = ineed::answers::Answers::new().answer("port", "8080");
+    /// let port = answers
+    ///   .prompt(ineed::written::<u16>("Port").key("port"))
+    ///   .unwrap();
+    /// ```
+    fn key<K>(self, key: K) -> Keyed<Self>
+    where
+        Self: Sized,
+        K: Into<String>,
+    {
+        Keyed {
+            prompt: self,
+            key: key.into(),
+            answers: None,
+        }
+    }
+
+    /// Takes the value from an environment variable if it's set, instead of prompting the user.
+    ///
+    /// The value of the variable is interpreted exactly like a line entered by the user, so it is
+    /// parsed with the same rules as the promptable (e.g. with [`FromStr`](std::str::FromStr) for
+    /// a [written] prompt, `yes` or `no` for a [boolean](bool()) prompt, the label or the index
+    /// of the item for a [selected] prompt, etc). If the variable isn't set, the user is prompted
+    /// as usual.
+    ///
+    /// # Errors
+    ///
+    /// If the value of the variable is rejected by the promptable, an error of kind
+    /// [`InvalidData`](io::ErrorKind::InvalidData) is returned, instead of prompting the user.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ineed::prelude::*;
+    /// let port = ineed::written::<u16>("Port")
+    ///   .or_env("APP_PORT")
+    ///   .prompt()
+    ///   .unwrap();
+    /// ```
+    fn or_env<V>(self, var: V) -> OrEnv<Self>
+    where
+        Self: Sized,
+        V: Into<String>,
+    {
+        OrEnv {
+            prompt: self,
+            var: var.into(),
+            lookup: |var| env::var_os(var),
+        }
+    }
+}
+
+/// Prompts the user for an input until it's valid, with the provided format rules.
+///
+/// The chains also use it to prompt their following steps.
+pub(crate) fn prompt_with_fmt<P, R, W>(
+    prompt: &mut P, mut read: R, mut write: W, fmt: &P::FmtRules,
+) -> io::Result<P::Output>
+where
+    P: Promptable + ?Sized,
+    R: BufRead,
+    W: Write,
+{
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        match prompt.prompt_once(&mut read, &mut write, &fmt.with_attempt(attempt))? {
+            ControlFlow::Break(out) => return Ok(out),
+            ControlFlow::Continue(rejection) => rejection.write_to(&mut write)?,
+        }
+    }
+}
+
+/// Prompts the user for an input asynchronously, until it's valid, with the provided format rules.
+///
+/// The chains also use it to prompt their following steps.
+#[cfg(feature = "async")]
+pub(crate) async fn prompt_with_fmt_async<P, R, W>(
+    prompt: &mut P, mut read: R, mut write: W, fmt: &P::FmtRules,
+) -> io::Result<P::Output>
+where
+    P: AsyncPromptable + ?Sized,
+    R: tokio::io::AsyncBufRead + Unpin,
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        let fmt = fmt.with_attempt(attempt);
+        match prompt
+            .prompt_once_async(&mut read, &mut write, &fmt)
+            .await?
+        {
+            ControlFlow::Break(out) => return Ok(out),
+            ControlFlow::Continue(rejection) => rejection.write_to_async(&mut write).await?,
+        }
+    }
+}
+
+/// Represents types that can be prompted to the console asynchronously, with the [`tokio`] IO
+/// traits.
+///
+/// This is the asynchronous version of the [`Promptable`] trait. It is implemented for every
+/// promptable type and wrapper of this crate, except the `password` promptable (as it reads
+/// from the TTY directly), so you can build your prompts the same way.
+///
+/// # Example
+///
+/// ```no_run
+/// # use ineed::prelude::*;
+/// # async fn run() {
+/// let (name, age) = ineed::written::<String>("Your name")
+///   .then(ineed::written::<u8>("Your age"))
+///   .prompt_async()
+///   .await
+///   .unwrap();
+/// # }
+/// ```
+#[cfg(feature = "async")]
+#[cfg_attr(nightly, doc(cfg(feature = "async")))]
+#[cfg_attr(nightly, doc(notable_trait))]
+pub trait AsyncPromptable: Promptable {
+    /// Prompts the user for an input, asynchronously.
+    ///
+    /// This has the same behavior as [`Promptable::prompt_once`].
+    fn prompt_once_async<R, W>(
+        &mut self, read: R, write: W, fmt: &Self::FmtRules,
+    ) -> impl Future<Output = io::Result<ControlFlow<Self::Output, Rejection>>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin;
+
+    /// Prompts the user for an input until it's valid, asynchronously.
+    ///
+    /// This has the same behavior as [`Promptable::prompt_with`].
+    fn prompt_with_async<R, W>(
+        &mut self, read: R, write: W,
+    ) -> impl Future<Output = io::Result<Self::Output>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        let fmt = format::default_rules();
+        async move { prompt_with_fmt_async(self, read, write, &fmt).await }
+    }
+
+    /// Prompts the user for an input until it's valid, using the standard input and output,
+    /// asynchronously.
+    ///
+    /// The [style rules](format::FmtRule::styled) are applied if the standard output is
+    /// a terminal.
+    fn prompt_async(&mut self) -> impl Future<Output = io::Result<Self::Output>> {
+        let fmt = format::stdout_rules();
+        async move {
+            let read = tokio::io::BufReader::new(tokio::io::stdin());
+            prompt_with_fmt_async(self, read, tokio::io::stdout(), &fmt).await
+        }
+    }
+}
+
+/// Represents types that can be prompted in raw mode, with the [`crossterm`] backend.
+///
+/// Instead of entering a line of text, the user navigates the prompt with the keys of the
+/// keyboard, e.g. the arrow keys and Enter for selectable prompts. It is implemented for the
+/// [`selected()`] and [`selected_list()`] promptables, and the [`fmt`](Promptable::fmt)
+/// wrapper. The line-based prompt of the [`Promptable`] trait is still the default.
+///
+/// See the [`raw`] module documentation for more information.
+#[cfg(feature = "crossterm")]
+#[cfg_attr(nightly, doc(cfg(feature = "crossterm")))]
+#[cfg_attr(nightly, doc(notable_trait))]
+pub trait RawPromptable: Promptable {
+    /// Prompts the user in raw mode, with the keys read from the provided key source.
+    ///
+    /// The terminal must already be in raw mode. Unlike [`Promptable::prompt_once`], this only
+    /// returns when the user made a choice, as the keys that aren't valid are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Any IO error is returned as is. If the user cancels the prompt (e.g. with Escape or
+    /// Ctrl-C), an error of kind [`Interrupted`](io::ErrorKind::Interrupted) is returned.
+    fn prompt_raw_once<K, W>(
+        &mut self, keys: K, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<Self::Output>
+    where
+        K: raw::KeySource,
+        W: Write;
+
+    /// Prompts the user in raw mode, with the keys read from the provided key source.
+    ///
+    /// This is useful to test the prompt with a [`VirtualTerminal`](raw::VirtualTerminal).
+    fn prompt_raw_with<K, W>(&mut self, keys: K, write: W) -> io::Result<Self::Output>
+    where
+        K: raw::KeySource,
+        W: Write,
+    {
+        let fmt = format::default_rules();
+        self.prompt_raw_once(keys, write, &fmt)
+    }
+
+    /// Prompts the user in raw mode, using the real terminal and the standard output.
+    ///
+    /// The terminal is put in raw mode during the prompt. If the standard input or output isn't
+    /// a terminal (e.g. when it's piped), this falls back to the line-based
+    /// [`Promptable::prompt`] method.
+    fn prompt_raw(&mut self) -> io::Result<Self::Output> {
+        use std::io::IsTerminal as _;
+
+        if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+            return self.prompt();
+        }
+
+        let fmt = format::stdout_rules();
+        let _guard = raw::RawModeGuard::enable()?;
+        self.prompt_raw_once(raw::Terminal::new(), io::stdout(), &fmt)
+    }
+}
+
+/// Represents types that can be built from the user input.
+///
+/// This is mostly implemented with the `Promptable` derive macro, which
+/// requires the `derive` feature.
+///
+/// # Example
+///
+/// ```no_run
+/// # use ineed::prelude::*;
+/// struct Point {
+///   x: i32,
+///   y: i32,
+/// }
+///
+/// impl ineed::FromPrompt for Point {
+///   fn promptable() -> impl ineed::Promptable<Output = Self> {
+///     ineed::written::<i32>("X")
+///       .then(ineed::written::<i32>("Y"))
+///       .map(|(x, y)| Self { x, y })
+///   }
+/// }
+///
+/// let point = Point::prompt().unwrap();
+/// ```
+pub trait FromPrompt: Sized {
+    /// Returns the promptable that builds this type.
+    fn promptable() -> impl Promptable<Output = Self>;
+
+    /// Prompts the user for this type, using the standard input and output.
+    ///
+    /// This is a shortcut for `Self::promptable().prompt()`.
+    fn prompt() -> io::Result<Self> {
+        Self::promptable().prompt()
+    }
+}
+
+/// Represents types that can be selected by the user in a list.
+///
+/// This is mostly implemented with the `Selectable` derive macro, which requires the `derive`
+/// feature.
+///
+/// # Example
+///
+/// ```no_run
+/// # use ineed::prelude::*;
+/// use ineed::format::rules::SelectedFmtRules;
+///
+/// enum Level {
+///   Good,
+///   Bad,
+/// }
+///
+/// impl ineed::Selectable for Level {
+///   fn prompt_select<'fmt>(
+///     msg: &str,
+///   ) -> impl ineed::Promptable<Output = Self, FmtRules = SelectedFmtRules<'fmt>> {
+///     ineed::selected(msg, [("Good", Self::Good), ("Bad", Self::Bad)])
+///   }
+/// }
+///
+/// let level = Level::prompt_select("Your level").prompt().unwrap();
+/// ```
+pub trait Selectable: Sized {
+    /// Returns a promptable that asks the user to choose a value of this type, with the provided
+    /// message.
+    fn prompt_select<'fmt>(
+        msg: &str,
+    ) -> impl Promptable<Output = Self, FmtRules = SelectedFmtRules<'fmt>>;
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use crate::{FromPrompt, Selectable, prelude::*};
+
+    #[derive(crate::Promptable, Debug, PartialEq)]
+    struct Config {
+        name: String,
+        #[ineed(msg = "Port", default = 8080)]
+        port: u16,
+        verbose: bool,
+    }
+
+    #[test]
+    fn derive_struct() -> anyhow::Result<()> {
+        let input = "foo\n\nyes\n".as_bytes();
+        let res = Config::promptable().prompt_with(input, std::io::empty())?;
+        assert_eq!(
+            res,
+            Config {
+                name: "foo".to_owned(),
+                port: 8080,
+                verbose: true,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn derive_output() -> anyhow::Result<()> {
+        let input = "foo\n\nyes\n".as_bytes();
+        let mut output = Vec::new();
+        Config::promptable().prompt_with(input, &mut output)?;
+        assert_eq!(
+            String::from_utf8(output)?,
+            "- Name\n> - Port [default: 8080]\n> - Verbose\n> "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn derive_keys() -> anyhow::Result<()> {
+        #[derive(crate::Promptable)]
+        struct Keys {
+            #[ineed(key = "name")]
+            name: String,
+            #[ineed(key = "port", until = |port| *port > 1024)]
+            port: u16,
+        }
+
+        let answers = crate::answers::Answers::new().answer("port", "80");
+        let res =
+            answers.scope(|| Keys::promptable().prompt_with("foo\n".as_bytes(), std::io::empty()));
+        assert!(matches!(res, Err(e) if e.kind() == std::io::ErrorKind::InvalidData));
+
+        let answers = answers.answer("port", "8080");
+        let res = answers
+            .scope(|| Keys::promptable().prompt_with("foo\n".as_bytes(), std::io::empty()))?;
+        assert_eq!((res.name.as_str(), res.port), ("foo", 8080));
+
+        Ok(())
+    }
+
+    #[derive(crate::Promptable, Debug, PartialEq)]
+    #[ineed(fmt = crate::fmt().input_prefix(""))]
+    struct Tuple(
+        #[ineed(msg = "A", until = |a| *a > 3, until_msg = "too small")] u8,
+        #[ineed(msg = "B", selected = [("x", 'x'), ("y", 'y')])] char,
+    );
+
+    #[test]
+    fn derive_tuple_struct() -> anyhow::Result<()> {
+        let input = "2\n4\n2\n".as_bytes();
+        let mut output = Vec::new();
+        let res = Tuple::promptable().prompt_with(input, &mut output)?;
+        assert_eq!(res, Tuple(4, 'y'));
+        assert_eq!(
+            String::from_utf8(output)?,
+            "- A\n! too small\n[1] - x\n[2] - y\n- B\n"
+        );
+
+        Ok(())
+    }
+
+    #[derive(crate::Promptable, Debug, PartialEq)]
+    struct Tries<T: std::str::FromStr<Err: std::fmt::Display> + Default> {
+        #[ineed(max_tries = 2, max_tries_fallback = T::default())]
+        value: T,
+        #[ineed(max_tries = 1, default = 3, max_tries_fallback = -1)]
+        other: i32,
+        #[ineed(selected = [("x", 'x'), ("y", 'y')], default = 1, max_tries = 1, max_tries_fallback = 'z')]
+        choice: char,
+    }
+
+    #[test]
+    fn derive_max_tries() -> anyhow::Result<()> {
+        let input = "a\nb\nc\nd\n".as_bytes();
+        let res = Tries::<u8>::promptable().prompt_with(input, std::io::empty())?;
+        assert_eq!(
+            res,
+            Tries {
+                value: 0,
+                other: -1,
+                choice: 'z'
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn derive_eof_input() {
+        let res = Config::promptable().prompt_with("foo\n".as_bytes(), std::io::empty());
+        assert!(matches!(res, Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof));
+    }
+
+    #[derive(crate::Selectable, Debug, PartialEq)]
+    #[ineed(label_match = crate::LabelMatch::Prefix)]
+    enum Level {
+        Good,
+        #[ineed(label = "So-so", default)]
+        Medium,
+        Bad,
+    }
+
+    #[test]
+    fn derive_fieldless_enum() -> anyhow::Result<()> {
+        let input = "4
+3
+"
+        .as_bytes();
+        let mut output = Vec::new();
+        let res = Level::prompt_select("Level").prompt_with(input, &mut output)?;
+        assert_eq!(res, Level::Bad);
+        assert_eq!(
+            String::from_utf8(output)?,
+            "[1] - Good\n[2] - So-so\n[3] - Bad\n- Level [default: 2]\n> \
+             ! choose a number between 1 and 3\n> "
+        );
+
+        let res = Level::prompt_select("").prompt_with("\n".as_bytes(), std::io::empty())?;
+        assert_eq!(res, Level::Medium);
+
+        let res = Level::prompt_select("").prompt_with("so\n".as_bytes(), std::io::empty())?;
+        assert_eq!(res, Level::Medium);
+
+        Ok(())
+    }
+
+    #[derive(crate::Selectable, Debug, PartialEq)]
+    enum Choice {
+        None,
+        Number(u8),
+        Point {
+            x: i32,
+            #[ineed(msg = "Y", default = 0)]
+            y: i32,
+        },
+    }
+
+    #[test]
+    fn derive_enum_with_payload() -> anyhow::Result<()> {
+        let res = Choice::prompt_select("").prompt_with("1\n".as_bytes(), std::io::empty())?;
+        assert_eq!(res, Choice::None);
+
+        let input = "2\nfoo\n7\n".as_bytes();
+        let mut output = Vec::new();
+        let res = Choice::prompt_select("Choice").prompt_with(input, &mut output)?;
+        assert_eq!(res, Choice::Number(7));
+        assert_eq!(
+            String::from_utf8(output)?,
+            "[1] - None\n[2] - Number\n[3] - Point\n- Choice\n> \
+             - Number\n> ! invalid digit found in string\n- Number\n> "
+        );
+
+        let input = "3\n-4\n\n".as_bytes();
+        let res = Choice::prompt_select("").prompt_with(input, std::io::empty())?;
+        assert_eq!(res, Choice::Point { x: -4, y: 0 });
+
+        Ok(())
+    }
+
+    #[test]
+    fn derive_enum_then() -> anyhow::Result<()> {
+        let input = "foo\n2\n".as_bytes();
+        let (name, level) = crate::written::<String>("")
+            .then(Level::prompt_select(""))
+            .prompt_with(input, std::io::empty())?;
+        assert_eq!(name, "foo");
+        assert_eq!(level, Level::Medium);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,201 @@
+use std::{io, marker::PhantomData, ops::ControlFlow};
+
+use crate::{
+    FromOutput, Promptable, Rejection,
+    format::{Progress as _, rules::ThenFmtRules},
+    promptables::Flattenable,
+};
+
+/// Wrapper for chaining prompts, where the second prompt depends on the output of the first one.
+///
+/// See the [`Promptable::and_then()`] method for more information.
+pub struct AndThen<A, F, P, O> {
+    pub(crate) first: A,
+    pub(crate) then: F,
+    pub(crate) _marker: PhantomData<fn() -> (P, O)>,
+}
+
+impl<A: Clone, F: Clone, P, O> Clone for AndThen<A, F, P, O> {
+    fn clone(&self) -> Self {
+        Self {
+            first: self.first.clone(),
+            then: self.then.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<A, F, P, O> Flattenable for AndThen<A, F, P, O>
+where
+    A: Promptable,
+    P: Promptable,
+{
+    type RawOutput = (<A as Promptable>::Output, <P as Promptable>::Output);
+}
+
+impl<A, F, P, O> Promptable for AndThen<A, F, P, O>
+where
+    A: Promptable,
+    F: FnMut(&<A as Promptable>::Output) -> P,
+    P: Promptable,
+    O: FromOutput<<Self as Flattenable>::RawOutput>,
+{
+    type Output = O;
+    type FmtRules = ThenFmtRules<<A as Promptable>::FmtRules, <P as Promptable>::FmtRules>;
+
+    fn prompt_once<R, W>(
+        &mut self, mut read: R, mut write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<O, Rejection>>
+    where
+        R: io::BufRead,
+        W: io::Write,
+    {
+        let fmt = fmt.root();
+
+        let a = match self
+            .first
+            .prompt_once(&mut read, &mut write, &fmt.a_rules)?
+        {
+            ControlFlow::Break(a) => a,
+            ControlFlow::Continue(rejection) => return Ok(ControlFlow::Continue(rejection)),
+        };
+
+        let mut then = (self.then)(&a);
+        let b = crate::prompt_with_fmt(&mut then, read, write, &fmt.b_rules)?;
+
+        Ok(ControlFlow::Break(FromOutput::from_output((a, b))))
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(nightly, doc(cfg(feature = "async")))]
+impl<A, F, P, O> crate::AsyncPromptable for AndThen<A, F, P, O>
+where
+    A: crate::AsyncPromptable,
+    F: FnMut(&<A as Promptable>::Output) -> P,
+    P: crate::AsyncPromptable,
+    O: FromOutput<<Self as Flattenable>::RawOutput>,
+{
+    async fn prompt_once_async<R, W>(
+        &mut self, mut read: R, mut write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<O, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        let fmt = fmt.root();
+
+        let a = match self
+            .first
+            .prompt_once_async(&mut read, &mut write, &fmt.a_rules)
+            .await?
+        {
+            ControlFlow::Break(a) => a,
+            ControlFlow::Continue(rejection) => return Ok(ControlFlow::Continue(rejection)),
+        };
+
+        let mut then = (self.then)(&a);
+        let b = crate::prompt_with_fmt_async(&mut then, read, write, &fmt.b_rules).await?;
+
+        Ok(ControlFlow::Break(FromOutput::from_output((a, b))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn cities(country: &str) -> Vec<(&'static str, &'static str)> {
+        match country {
+            "France" => vec![("Paris", "Paris"), ("Lyon", "Lyon")],
+            _ => vec![("Berlin", "Berlin"), ("Munich", "Munich")],
+        }
+    }
+
+    #[test]
+    fn dependent_list() -> anyhow::Result<()> {
+        let input = "2\n3\n2\n".as_bytes();
+        let mut output = Vec::new();
+        let (country, city) =
+            crate::selected("Country", [("France", "France"), ("Germany", "Germany")])
+                .and_then(|country| crate::selected_list("City", cities(country)))
+                .fmt(
+                    crate::fmt()
+                        .list_surrounds("", ". ")
+                        .msg_prefix("")
+                        .msg_suffix(": ")
+                        .input_prefix("")
+                        .break_line(false),
+                )
+                .prompt_with(input, &mut output)?;
+
+        assert_eq!((country, city), ("Germany", "Munich"));
+        assert_eq!(
+            String::from_utf8(output)?,
+            "1. France\n2. Germany\nCountry: \
+            1. Berlin\n2. Munich\nCity: ! choose a number between 1 and 2\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn flattened_output() -> anyhow::Result<()> {
+        let input = "1\n2\n5\n".as_bytes();
+        let (a, b, c) = crate::written::<i32>("a")
+            .then(crate::written::<i32>("b"))
+            .and_then(|&(a, b)| crate::written::<i32>("c").until(move |c| *c == a + b + 2))
+            .prompt_with(input, std::io::empty())?;
+
+        assert_eq!((a, b, c), (1, 2, 5));
+
+        Ok(())
+    }
+
+    #[test]
+    fn first_rejected() -> anyhow::Result<()> {
+        let input = "foo\n1\n2\n".as_bytes();
+        let mut calls = 0;
+        let (a, b) = crate::written::<i32>("a")
+            .and_then(|&a| {
+                calls += 1;
+                crate::written::<i32>("b").until(move |b| *b > a)
+            })
+            .prompt_with(input, std::io::empty())?;
+
+        assert_eq!((a, b, calls), (1, 2, 1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn step_prefix() -> anyhow::Result<()> {
+        let input = "1\n2\n3\n".as_bytes();
+        let mut output = Vec::new();
+        let (a, (b, c)): (i32, (i32, i32)) = crate::written::<i32>("a")
+            .and_then(|_| crate::written::<i32>("b").then(crate::written::<i32>("c")))
+            .fmt(crate::fmt().inline().step_prefix("[{step}/{total}] "))
+            .prompt_with(input, &mut output)?;
+
+        assert_eq!((a, b, c), (1, 2, 3));
+        assert_eq!(String::from_utf8(output)?, "[1/3] a: [2/3] b: [3/3] c: ");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_dependent_prompt() -> anyhow::Result<()> {
+        let (mut input, read) = tokio::io::duplex(1024);
+        tokio::io::AsyncWriteExt::write_all(&mut input, b"3\n2\n4\n").await?;
+        drop(input);
+
+        let (a, b) = crate::written::<i32>("a")
+            .and_then(|&a| crate::written::<i32>("b").until(move |b| *b > a))
+            .prompt_with_async(tokio::io::BufReader::new(read), tokio::io::sink())
+            .await?;
+        assert_eq!((a, b), (3, 4));
+
+        Ok(())
+    }
+}
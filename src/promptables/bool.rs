@@ -1,27 +1,70 @@
 use std::{io, ops::ControlFlow};
 
-use crate::{Promptable, WrittenFmtRules, WrittenInner};
+use crate::{Promptable, Rejection, WrittenFmtRules, WrittenInner};
 
 /// Promptable type for boolean inputs, like yes or no.
 ///
 /// See the [`bool()`] function for more information.
+#[derive(Clone)]
 pub struct Bool<'a, 'fmt> {
     inner: WrittenInner<'a, 'fmt>,
+    default: Option<bool>,
+}
+
+impl Bool<'_, '_> {
+    /// Gives a default value to the prompt, returned when the user enters an empty input.
+    ///
+    /// The default value is displayed right after the message, with the
+    /// [`default_surrounds`](crate::format::FmtRule::default_surrounds) format rule.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ineed::prelude::*;
+    /// let proceed = ineed::bool("Proceed?").default(true).prompt().unwrap();
+    /// ```
+    ///
+    /// The above example will show something similar to this:
+    /// ```txt
+    /// - Proceed? [Y/n]
+    /// >
+    /// ```
+    pub fn default(mut self, value: bool) -> Self {
+        self.inner
+            .set_default_hint(if value { "Y/n" } else { "y/N" }.to_owned());
+        self.default = Some(value);
+        self
+    }
 }
 
 /// Returns a type that prompts the user for a boolean value, in a natural way.
 ///
 /// Natural way means answering by "yes" or "no" for example.
 ///
-/// It is similar to the [`written`](crate::written) promptable.
+/// It is similar to the [`written`](crate::written) promptable. If the input isn't recognized as
+/// a yes or a no, the user is asked to answer by yes or no.
 pub fn bool(msg: &str) -> Bool<'_, '_> {
     Bool {
         inner: WrittenInner::new(msg),
+        default: None,
     }
 }
 
 const TRUE_INPUTS: &[&str] = &["y", "ye", "yes", "yep", "true"];
 const FALSE_INPUTS: &[&str] = &["n", "no", "nop", "nope", "nopp", "na", "nah", "false"];
+const INVALID_INPUT: &str = "please answer with yes or no";
+
+impl Bool<'_, '_> {
+    fn parse(&self, input: &str, fmt: &WrittenFmtRules<'_>) -> ControlFlow<bool, Rejection> {
+        let input = input.trim().to_lowercase();
+        match self.default {
+            Some(default) if input.is_empty() => ControlFlow::Break(default),
+            _ if TRUE_INPUTS.contains(&input.as_str()) => ControlFlow::Break(true),
+            _ if FALSE_INPUTS.contains(&input.as_str()) => ControlFlow::Break(false),
+            _ => ControlFlow::Continue(Rejection::new(INVALID_INPUT, fmt)),
+        }
+    }
+}
 
 impl<'fmt> Promptable for Bool<'_, 'fmt> {
     type Output = bool;
@@ -29,17 +72,28 @@ impl<'fmt> Promptable for Bool<'_, 'fmt> {
 
     fn prompt_once<R, W>(
         &mut self, read: R, write: W, fmt: &Self::FmtRules,
-    ) -> io::Result<ControlFlow<Self::Output>>
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
     where
         R: io::BufRead,
         W: io::Write,
     {
-        let input = self.inner.prompt(read, write, fmt)?.trim().to_lowercase();
-        Ok(match () {
-            _ if TRUE_INPUTS.contains(&input.as_str()) => ControlFlow::Break(true),
-            _ if FALSE_INPUTS.contains(&input.as_str()) => ControlFlow::Break(false),
-            _ => ControlFlow::Continue(()),
-        })
+        let input = self.inner.prompt(read, write, fmt)?;
+        Ok(self.parse(&input, fmt))
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(nightly, doc(cfg(feature = "async")))]
+impl crate::AsyncPromptable for Bool<'_, '_> {
+    async fn prompt_once_async<R, W>(
+        &mut self, mut read: R, mut write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        let input = self.inner.prompt_async(&mut read, &mut write, fmt).await?;
+        Ok(self.parse(&input, fmt))
     }
 }
 
@@ -61,7 +115,10 @@ mod tests {
             std::io::empty(),
             &WrittenFmtRules::default(),
         )?;
-        assert_eq!(res, ControlFlow::Continue(()));
+        assert!(matches!(
+            res,
+            ControlFlow::Continue(rejection) if rejection.reason() == super::INVALID_INPUT
+        ));
         Ok(())
     }
 
@@ -94,4 +151,61 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn eof_input() {
+        let res = crate::bool("").prompt_with("e\na\n".as_bytes(), std::io::empty());
+        assert_eq!(res.unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn default_value() -> anyhow::Result<()> {
+        let mut output = Vec::new();
+        let res = crate::bool("foo")
+            .default(true)
+            .fmt(
+                crate::fmt()
+                    .msg_prefix("")
+                    .break_line(false)
+                    .input_prefix(": "),
+            )
+            .prompt_with("\n".as_bytes(), &mut output)?;
+        assert!(res);
+        assert_eq!(String::from_utf8(output)?, "foo [Y/n]: ");
+
+        let mut output = Vec::new();
+        let res = crate::bool("foo")
+            .default(false)
+            .fmt(
+                crate::fmt()
+                    .msg_prefix("")
+                    .break_line(false)
+                    .input_prefix(": "),
+            )
+            .prompt_with("\n".as_bytes(), &mut output)?;
+        assert!(!res);
+        assert_eq!(String::from_utf8(output)?, "foo [y/N]: ");
+
+        let res = crate::bool("foo")
+            .default(false)
+            .prompt_with("yes\n".as_bytes(), std::io::empty())?;
+        assert!(res);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_input() -> anyhow::Result<()> {
+        let (mut input, read) = tokio::io::duplex(1024);
+        tokio::io::AsyncWriteExt::write_all(&mut input, b"e\nYeS\n").await?;
+        drop(input);
+
+        let res = crate::bool("")
+            .prompt_with_async(tokio::io::BufReader::new(read), tokio::io::sink())
+            .await?;
+        assert!(res);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,241 @@
+use std::{fmt::Display, io, ops::ControlFlow};
+
+use crate::{
+    Promptable, Rejection,
+    format::{Progress as _, rules::ConfirmFmtRules},
+    promptables::Bool,
+};
+
+/// Wrapper for promptable types to confirm the answer of the user.
+///
+/// See the [`Promptable::confirm()`] method for more information.
+#[derive(Clone)]
+pub struct Confirm<'a, P> {
+    pub(crate) prompt: P,
+    /// The prompt as it was before it was prompted, to restart from it.
+    pub(crate) initial: P,
+    pub(crate) msg: &'a str,
+}
+
+impl<P> Confirm<'_, P>
+where
+    P: Promptable,
+    <P as Promptable>::Output: Display,
+{
+    /// Returns the confirmation question of the provided answer.
+    fn question(&self, value: &<P as Promptable>::Output) -> String {
+        self.msg.replace("{value}", &value.to_string())
+    }
+}
+
+impl<'a, P> Promptable for Confirm<'a, P>
+where
+    P: Promptable + Clone,
+    <P as Promptable>::Output: Display,
+{
+    type Output = <P as Promptable>::Output;
+    type FmtRules =
+        ConfirmFmtRules<<P as Promptable>::FmtRules, <Bool<'a, 'a> as Promptable>::FmtRules>;
+
+    fn prompt_once<R, W>(
+        &mut self, mut read: R, mut write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: io::BufRead,
+        W: io::Write,
+    {
+        let fmt = fmt.root();
+
+        loop {
+            let value = match self
+                .prompt
+                .prompt_once(&mut read, &mut write, &fmt.a_rules)?
+            {
+                ControlFlow::Break(value) => value,
+                ControlFlow::Continue(rejection) => return Ok(ControlFlow::Continue(rejection)),
+            };
+
+            let question = self.question(&value);
+            let mut question = crate::bool(&question);
+            let confirmed =
+                crate::prompt_with_fmt(&mut question, &mut read, &mut write, &fmt.b_rules)?;
+
+            if confirmed {
+                return Ok(ControlFlow::Break(value));
+            }
+            self.prompt = self.initial.clone();
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(nightly, doc(cfg(feature = "async")))]
+impl<P> crate::AsyncPromptable for Confirm<'_, P>
+where
+    P: crate::AsyncPromptable + Clone,
+    <P as Promptable>::Output: Display,
+{
+    async fn prompt_once_async<R, W>(
+        &mut self, mut read: R, mut write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        let fmt = fmt.root();
+
+        loop {
+            let value = match self
+                .prompt
+                .prompt_once_async(&mut read, &mut write, &fmt.a_rules)
+                .await?
+            {
+                ControlFlow::Break(value) => value,
+                ControlFlow::Continue(rejection) => return Ok(ControlFlow::Continue(rejection)),
+            };
+
+            let question = self.question(&value);
+            let mut question = crate::bool(&question);
+            let confirmed =
+                crate::prompt_with_fmt_async(&mut question, &mut read, &mut write, &fmt.b_rules)
+                    .await?;
+
+            if confirmed {
+                return Ok(ControlFlow::Break(value));
+            }
+            self.prompt = self.initial.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn restart_on_no() -> anyhow::Result<()> {
+        let input = "foo\nno\nbar\nmaybe\nyes\n".as_bytes();
+        let mut output = Vec::new();
+        let res = crate::written::<String>("Name")
+            .confirm("Is {value} correct?")
+            .fmt(crate::fmt().inline())
+            .prompt_with(input, &mut output)?;
+
+        assert_eq!(res, "bar");
+        assert_eq!(
+            String::from_utf8(output)?,
+            "Name: Is foo correct?: Name: Is bar correct?: \
+            ! please answer with yes or no\nIs bar correct?: "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn restart_without_inline() -> anyhow::Result<()> {
+        let input = "foo\nn\nbar\ny\n".as_bytes();
+        let mut output = Vec::new();
+        let res = crate::written::<String>("Name")
+            .confirm("Is {value} correct?")
+            .prompt_with(input, &mut output)?;
+
+        assert_eq!(res, "bar");
+        assert_eq!(
+            String::from_utf8(output)?,
+            "- Name\n> - Is foo correct?\n> - Name\n> - Is bar correct?\n> "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn pick_same_item_again() -> anyhow::Result<()> {
+        let input = "1\nn\n1\ny\n".as_bytes();
+        let mut output = Vec::new();
+        let res = crate::selected("License", [("MIT", 1), ("GPL", 2)])
+            .confirm("Use {value}?")
+            .fmt(crate::fmt().inline())
+            .prompt_with(input, &mut output)?;
+
+        assert_eq!(res, 1);
+        assert_eq!(
+            String::from_utf8(output)?,
+            "[1] - MIT\n[2] - GPL\nLicense: Use 1?: \
+            [1] - MIT\n[2] - GPL\nLicense: Use 1?: "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejected_answer() -> anyhow::Result<()> {
+        let input = "foo\n42\ny\n".as_bytes();
+        let res = crate::written::<u8>("Age")
+            .confirm("{value} years old?")
+            .prompt_with(input, std::io::empty())?;
+        assert_eq!(res, 42);
+
+        Ok(())
+    }
+
+    #[test]
+    fn chained() -> anyhow::Result<()> {
+        let input = "foo\ny\n2\nn\n1\ny\n".as_bytes();
+        let mut output = Vec::new();
+        let (name, license) = crate::written::<String>("Name")
+            .confirm("Is {value} correct?")
+            .then(
+                crate::selected("License", [("MIT", "MIT"), ("Apache", "Apache-2.0")])
+                    .fmt(crate::fmt().list_surrounds("", ". "))
+                    .confirm("Use {value}?"),
+            )
+            .fmt(crate::fmt().inline().step_prefix("[{step}/{total}] "))
+            .prompt_with(input, &mut output)?;
+
+        assert_eq!((name.as_str(), license), ("foo", "MIT"));
+        assert_eq!(
+            String::from_utf8(output)?,
+            "[1/2] Name: [1/2] Is foo correct?: \
+            1. MIT\n2. Apache\n[2/2] License: [2/2] Use Apache-2.0?: \
+            1. MIT\n2. Apache\n[2/2] License: [2/2] Use MIT?: "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn confirmed_chain() -> anyhow::Result<()> {
+        let input = "foo\nbar\ny\n".as_bytes();
+        let mut output = Vec::new();
+        let res = crate::written::<String>("First")
+            .then(crate::written::<String>("Last"))
+            .map(|(first, last)| format!("{first} {last}"))
+            .confirm("Is {value} correct?")
+            .fmt(crate::fmt().inline().step_prefix("[{step}/{total}] "))
+            .prompt_with(input, &mut output)?;
+
+        assert_eq!(res, "foo bar");
+        assert_eq!(
+            String::from_utf8(output)?,
+            "[1/2] First: [2/2] Last: [2/2] Is foo bar correct?: "
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_restart_on_no() -> anyhow::Result<()> {
+        let (mut input, read) = tokio::io::duplex(1024);
+        tokio::io::AsyncWriteExt::write_all(&mut input, b"1\nnope\n2\nyep\n").await?;
+        drop(input);
+
+        let res = crate::written::<i32>("")
+            .confirm("{value}?")
+            .prompt_with_async(tokio::io::BufReader::new(read), tokio::io::sink())
+            .await?;
+        assert_eq!(res, 2);
+
+        Ok(())
+    }
+}
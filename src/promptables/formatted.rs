@@ -1,10 +1,11 @@
 use std::{io, ops::ControlFlow};
 
-use crate::{Promptable, format::Mergeable as _};
+use crate::{Promptable, Rejection, format::Mergeable as _};
 
 /// Wrapper for promptable types to customize the prompt format.
 ///
 /// See the [`Promptable::fmt()`] method for more information.
+#[derive(Clone)]
 pub struct Formatted<P: Promptable> {
     pub(crate) prompt: P,
     pub(crate) rules: <P as Promptable>::FmtRules,
@@ -16,7 +17,7 @@ impl<P: Promptable> Promptable for Formatted<P> {
 
     fn prompt_once<R, W>(
         &mut self, read: R, write: W, fmt: &Self::FmtRules,
-    ) -> io::Result<ControlFlow<Self::Output>>
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
     where
         R: io::BufRead,
         W: io::Write,
@@ -25,3 +26,62 @@ impl<P: Promptable> Promptable for Formatted<P> {
         self.prompt.prompt_once(read, write, &fmt)
     }
 }
+
+#[cfg(feature = "async")]
+#[cfg_attr(nightly, doc(cfg(feature = "async")))]
+impl<P: crate::AsyncPromptable> crate::AsyncPromptable for Formatted<P> {
+    async fn prompt_once_async<R, W>(
+        &mut self, read: R, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        let fmt = self.rules.merge_with(fmt);
+        self.prompt.prompt_once_async(read, write, &fmt).await
+    }
+}
+
+#[cfg(feature = "crossterm")]
+#[cfg_attr(nightly, doc(cfg(feature = "crossterm")))]
+impl<P: crate::RawPromptable> crate::RawPromptable for Formatted<P> {
+    fn prompt_raw_once<K, W>(
+        &mut self, keys: K, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<Self::Output>
+    where
+        K: crate::raw::KeySource,
+        W: io::Write,
+    {
+        let fmt = self.rules.merge_with(fmt);
+        self.prompt.prompt_raw_once(keys, write, &fmt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn eof_input() {
+        let res = crate::written::<i32>("")
+            .fmt(crate::fmt().input_prefix(">> "))
+            .prompt_with("nop\n".as_bytes(), std::io::empty());
+        assert_eq!(res.unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_eof_input() {
+        let (mut input, read) = tokio::io::duplex(1024);
+        tokio::io::AsyncWriteExt::write_all(&mut input, b"nop\n")
+            .await
+            .unwrap();
+        drop(input);
+
+        let res = crate::written::<i32>("")
+            .fmt(crate::fmt().input_prefix(">> "))
+            .prompt_with_async(tokio::io::BufReader::new(read), tokio::io::sink())
+            .await;
+        assert_eq!(res.unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+}
@@ -0,0 +1,81 @@
+use std::{io, ops::ControlFlow};
+
+use crate::{Promptable, Rejection, answers::Answers};
+
+/// Wrapper for promptable types to give them a stable key, used to find their answer in the
+/// installed [`Answers`](crate::answers::Answers).
+///
+/// See the [`Promptable::key()`] method for more information.
+#[derive(Clone)]
+pub struct Keyed<P> {
+    pub(crate) prompt: P,
+    pub(crate) key: String,
+    pub(crate) answers: Option<Answers>,
+}
+
+impl<P> Keyed<P> {
+    /// Takes the answer from the provided set, instead of the installed answers.
+    ///
+    /// This is required for asynchronous prompts polled outside of the
+    /// [`Answers::scope`] function, e.g. on a multi-threaded runtime.
+    /// See the [`answers`](crate::answers#asynchronous-prompts) module for more information.
+    pub fn answers(mut self, answers: &Answers) -> Self {
+        self.answers = Some(answers.clone());
+        self
+    }
+}
+
+impl<P: Promptable> Keyed<P> {
+    /// Interprets the answer found for the key, or returns `None` if the user must be prompted.
+    fn answer(&mut self, fmt: &P::FmtRules) -> io::Result<Option<P::Output>> {
+        let Some(answer) = crate::answers::resolve(self.answers.as_ref(), &self.key)? else {
+            return Ok(None);
+        };
+
+        match crate::prompt_from_str(&mut self.prompt, &answer, fmt)? {
+            ControlFlow::Break(out) => Ok(Some(out)),
+            ControlFlow::Continue(rejection) => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid answer for the key \"{}\": {rejection}", self.key),
+            )),
+        }
+    }
+}
+
+impl<P: Promptable> Promptable for Keyed<P> {
+    type Output = <P as Promptable>::Output;
+    type FmtRules = <P as Promptable>::FmtRules;
+
+    fn prompt_once<R, W>(
+        &mut self, read: R, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: io::BufRead,
+        W: io::Write,
+    {
+        match self.answer(fmt)? {
+            Some(out) => Ok(ControlFlow::Break(out)),
+            None => self.prompt.prompt_once(read, write, fmt),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(nightly, doc(cfg(feature = "async")))]
+impl<P> crate::AsyncPromptable for Keyed<P>
+where
+    P: crate::AsyncPromptable,
+{
+    async fn prompt_once_async<R, W>(
+        &mut self, read: R, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        match self.answer(fmt)? {
+            Some(out) => Ok(ControlFlow::Break(out)),
+            None => self.prompt.prompt_once_async(read, write, fmt).await,
+        }
+    }
+}
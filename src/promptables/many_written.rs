@@ -1,10 +1,11 @@
-use std::{io, marker::PhantomData, ops::ControlFlow, str::FromStr};
+use std::{fmt::Display, io, marker::PhantomData, ops::ControlFlow, str::FromStr};
 
-use crate::{Promptable, WrittenFmtRules, WrittenInner};
+use crate::{Promptable, Rejection, WrittenFmtRules, WrittenInner};
 
 /// Promptable type for many written inputs with different types.
 ///
 /// See the [`many_written()`] function for more information.
+#[derive(Clone)]
 pub struct ManyWritten<'a, 'fmt, const N: usize, O> {
     inner: WrittenInner<'a, 'fmt>,
     sep: &'a str,
@@ -82,7 +83,8 @@ impl_tup_to_strings! {
     note = "try to clarify the output type of the binding, e.g. with `let x: (_, _, ...) = ...;`"
 )]
 trait TryFromOutput<Output> {
-    fn try_from_output(output: Output) -> Option<Self>
+    /// Returns the reason of the failure if any value couldn't be parsed.
+    fn try_from_output(output: Output) -> Result<Self, String>
     where
         Self: Sized;
 }
@@ -106,13 +108,15 @@ macro_rules! impl_try_from_output {
         impl<$Head, $($Tail),*> TryFromOutput<(&str, $(<$Tail as StrType>::Str<'_>),*)> for ($Head, $($Tail),*)
         where
             $Head: FromStr,
-            $($Tail: FromStr),*
+            <$Head as FromStr>::Err: Display,
+            $($Tail: FromStr,
+            <$Tail as FromStr>::Err: Display),*
         {
             #[allow(non_snake_case)]
-            fn try_from_output(($Head, $($Tail),*): (&str, $(<$Tail as StrType>::Str<'_>),*)) -> Option<Self> {
-                Some((
-                    $Head.parse().ok()?,
-                    $($Tail.parse().ok()?),*
+            fn try_from_output(($Head, $($Tail),*): (&str, $(<$Tail as StrType>::Str<'_>),*)) -> Result<Self, String> {
+                Ok((
+                    $Head.parse().map_err(|e| format!("\"{}\": {e}", $Head))?,
+                    $($Tail.parse().map_err(|e| format!("\"{}\": {e}", $Tail))?),*
                 ))
             }
         }
@@ -132,6 +136,31 @@ impl_try_from_output! {
     V, W, X, Y, Z
 }
 
+fn parse<const N: usize, O>(
+    input: &str, sep: &str, fmt: &WrittenFmtRules<'_>,
+) -> ControlFlow<O, Rejection>
+where
+    O: StrTuple<N> + for<'a> TryFromOutput<<O as StrTuple<N>>::StrTuple<'a>>,
+    for<'a> <O as StrTuple<N>>::StrTuple<'a>: From<[&'a str; N]>,
+{
+    let strings: [_; N] = match input
+        .split(sep)
+        .map(|s| s.trim())
+        .collect::<Vec<_>>()
+        .try_into()
+    {
+        Ok(array) => array,
+        Err(_) => {
+            let reason = format!("please enter {N} values separated by \"{sep}\"");
+            return ControlFlow::Continue(Rejection::new(reason, fmt));
+        }
+    };
+    match TryFromOutput::try_from_output(strings.into()) {
+        Ok(out) => ControlFlow::Break(out),
+        Err(e) => ControlFlow::Continue(Rejection::new(e, fmt)),
+    }
+}
+
 impl<'fmt, const N: usize, O> Promptable for ManyWritten<'_, 'fmt, N, O>
 where
     O: StrTuple<N> + for<'a> TryFromOutput<<O as StrTuple<N>>::StrTuple<'a>>,
@@ -142,29 +171,37 @@ where
 
     fn prompt_once<R, W>(
         &mut self, read: R, write: W, fmt: &Self::FmtRules,
-    ) -> io::Result<ControlFlow<Self::Output>>
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
     where
         R: io::BufRead,
         W: io::Write,
     {
         let input = self.inner.prompt(read, write, fmt)?;
-        let strings: [_; N] = match input
-            .split(self.sep)
-            .map(|s| s.trim())
-            .collect::<Vec<_>>()
-            .try_into()
-        {
-            Ok(array) => array,
-            Err(_) => return Ok(ControlFlow::Continue(())),
-        };
-        match TryFromOutput::try_from_output(strings.into()) {
-            Some(out) => Ok(ControlFlow::Break(out)),
-            None => Ok(ControlFlow::Continue(())),
-        }
+        Ok(parse(&input, self.sep, fmt))
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(nightly, doc(cfg(feature = "async")))]
+impl<const N: usize, O> crate::AsyncPromptable for ManyWritten<'_, '_, N, O>
+where
+    O: StrTuple<N> + for<'a> TryFromOutput<<O as StrTuple<N>>::StrTuple<'a>>,
+    for<'a> <O as StrTuple<N>>::StrTuple<'a>: From<[&'a str; N]>,
+{
+    async fn prompt_once_async<R, W>(
+        &mut self, mut read: R, mut write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        let input = self.inner.prompt_async(&mut read, &mut write, fmt).await?;
+        Ok(parse(&input, self.sep, fmt))
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use crate::prelude::*;
 
@@ -206,4 +243,28 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn eof_input() {
+        let input = "foo, beg, true\n";
+        let res: std::io::Result<(String, i32, bool)> =
+            crate::many_written("msg", ", ").prompt_with(input.as_bytes(), std::io::empty());
+        assert_eq!(res.unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_any_invalid_input() -> anyhow::Result<()> {
+        let (mut input, read) = tokio::io::duplex(1024);
+        tokio::io::AsyncWriteExt::write_all(&mut input, b"foo, beg, true\nboor, 2, false\n")
+            .await?;
+        drop(input);
+
+        let (str, i32, bool): (String, i32, bool) = crate::many_written("msg", ", ")
+            .prompt_with_async(tokio::io::BufReader::new(read), tokio::io::sink())
+            .await?;
+        assert_eq!((str.as_str(), i32, bool), ("boor", 2, false));
+
+        Ok(())
+    }
 }
@@ -1,10 +1,11 @@
 use std::{io, ops::ControlFlow};
 
-use crate::Promptable;
+use crate::{Promptable, Rejection};
 
 /// Wrapper for promptable types to map the output into another value.
 ///
 /// See the [`Promptable::map()`] method for more information.
+#[derive(Clone)]
 pub struct Map<P, F> {
     pub(crate) prompt: P,
     pub(crate) map: F,
@@ -20,17 +21,45 @@ where
 
     fn prompt_once<R, W>(
         &mut self, read: R, write: W, fmt: &Self::FmtRules,
-    ) -> io::Result<ControlFlow<Self::Output>>
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
     where
         R: io::BufRead,
         W: io::Write,
     {
         self.prompt
             .prompt_once(read, write, fmt)
-            .map(|flow| match flow {
-                ControlFlow::Break(val) => ControlFlow::Break((self.map)(val)),
-                ControlFlow::Continue(_) => ControlFlow::Continue(()),
-            })
+            .map(|flow| self.map_flow(flow))
+    }
+}
+
+impl<P, F> Map<P, F> {
+    fn map_flow<O, T>(&mut self, flow: ControlFlow<O, Rejection>) -> ControlFlow<T, Rejection>
+    where
+        F: FnMut(O) -> T,
+    {
+        match flow {
+            ControlFlow::Break(val) => ControlFlow::Break((self.map)(val)),
+            ControlFlow::Continue(rejection) => ControlFlow::Continue(rejection),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(nightly, doc(cfg(feature = "async")))]
+impl<P, F, T> crate::AsyncPromptable for Map<P, F>
+where
+    P: crate::AsyncPromptable,
+    F: FnMut(<P as Promptable>::Output) -> T,
+{
+    async fn prompt_once_async<R, W>(
+        &mut self, read: R, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        let flow = self.prompt.prompt_once_async(read, write, fmt).await?;
+        Ok(self.map_flow(flow))
     }
 }
 
@@ -47,4 +76,28 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn eof_input() {
+        let res = crate::written::<i32>("")
+            .map(|x| x + 3)
+            .prompt_with("".as_bytes(), std::io::empty());
+        assert_eq!(res.unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_basic() -> anyhow::Result<()> {
+        let (mut input, read) = tokio::io::duplex(1024);
+        tokio::io::AsyncWriteExt::write_all(&mut input, b"3\n").await?;
+        drop(input);
+
+        let res = crate::written::<i32>("")
+            .map(|x| x + 3)
+            .prompt_with_async(tokio::io::BufReader::new(read), tokio::io::sink())
+            .await?;
+        assert_eq!(res, 6);
+
+        Ok(())
+    }
 }
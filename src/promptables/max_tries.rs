@@ -1,10 +1,11 @@
 use std::{io, ops::ControlFlow};
 
-use crate::Promptable;
+use crate::{Promptable, Rejection};
 
 /// Wrapper for promptable types to limit the amount of tries before having a correct input.
 ///
 /// See the [`Promptable::max_tries()`] method for more information.
+#[derive(Clone)]
 pub struct MaxTries<P> {
     pub(crate) prompt: P,
     pub(crate) current: usize,
@@ -26,22 +27,57 @@ where
 
     fn prompt_once<R, W>(
         &mut self, read: R, write: W, fmt: &Self::FmtRules,
-    ) -> io::Result<ControlFlow<Self::Output>>
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
     where
         R: io::BufRead,
         W: io::Write,
     {
+        if self.exceeded() {
+            return Ok(ControlFlow::Break(Err(MaxTriesExceeded(()))));
+        }
+
+        self.prompt.prompt_once(read, write, fmt).map(wrap_ok)
+    }
+}
+
+impl<P> MaxTries<P> {
+    /// Counts a new try, and returns whether the maximum amount of tries is exceeded.
+    fn exceeded(&mut self) -> bool {
         self.current += 1;
-        if self.current > self.max {
+        self.current > self.max
+    }
+}
+
+fn wrap_ok<O>(
+    flow: ControlFlow<O, Rejection>,
+) -> ControlFlow<Result<O, MaxTriesExceeded>, Rejection> {
+    match flow {
+        ControlFlow::Break(out) => ControlFlow::Break(Ok(out)),
+        ControlFlow::Continue(rejection) => ControlFlow::Continue(rejection),
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(nightly, doc(cfg(feature = "async")))]
+impl<P> crate::AsyncPromptable for MaxTries<P>
+where
+    P: crate::AsyncPromptable,
+{
+    async fn prompt_once_async<R, W>(
+        &mut self, read: R, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        if self.exceeded() {
             return Ok(ControlFlow::Break(Err(MaxTriesExceeded(()))));
         }
 
         self.prompt
-            .prompt_once(read, write, fmt)
-            .map(|flow| match flow {
-                ControlFlow::Break(out) => ControlFlow::Break(Ok(out)),
-                ControlFlow::Continue(_) => ControlFlow::Continue(()),
-            })
+            .prompt_once_async(read, write, fmt)
+            .await
+            .map(wrap_ok)
     }
 }
 
@@ -78,4 +114,39 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn eof_before_max_tries() {
+        let res = crate::written::<i32>("foo")
+            .max_tries(3)
+            .prompt_with("nop\na\n".as_bytes(), std::io::empty());
+        assert_eq!(res.unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn default_value() -> anyhow::Result<()> {
+        let res = crate::bool("foo")
+            .default(true)
+            .max_tries(2)
+            .prompt_with("nop?\n\n".as_bytes(), std::io::empty())?;
+        assert_eq!(res, Ok(true));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_max_tries_reached() -> anyhow::Result<()> {
+        let (mut input, read) = tokio::io::duplex(1024);
+        tokio::io::AsyncWriteExt::write_all(&mut input, b"nop\na\noo\n6").await?;
+        drop(input);
+
+        let res = crate::written::<i32>("foo")
+            .max_tries(3)
+            .prompt_with_async(tokio::io::BufReader::new(read), tokio::io::sink())
+            .await?;
+        assert_eq!(res, Err(crate::MaxTriesExceeded(())));
+
+        Ok(())
+    }
 }
@@ -1,25 +1,115 @@
+use std::{io, ops::ControlFlow};
+
+use crate::{Promptable, Rejection};
+
+mod and_then;
 mod bool;
+mod confirm;
 mod formatted;
+mod keyed;
 mod many_written;
 mod map;
 mod max_tries;
+mod multi_selected;
+mod or_else;
+mod or_env;
 #[cfg(feature = "rpassword")]
 mod password;
+mod repeat;
 mod selected;
+mod selected_list;
 mod separated;
 mod then;
+mod try_map;
 mod until;
+mod validate;
+mod variants;
 mod written;
 
+pub use and_then::*;
 pub use bool::*;
+pub use confirm::*;
 pub use formatted::*;
+pub use keyed::*;
 pub use many_written::*;
 pub use map::*;
 pub use max_tries::*;
+pub use multi_selected::*;
+pub use or_else::*;
+pub use or_env::*;
 #[cfg(feature = "rpassword")]
 pub use password::*;
+pub use repeat::*;
 pub use selected::*;
+pub use selected_list::*;
 pub use separated::*;
 pub use then::*;
+pub use try_map::*;
 pub use until::*;
+pub use validate::*;
+pub use variants::*;
 pub use written::*;
+
+/// Reads a line from the input stream.
+///
+/// Returns an error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) if the end of the
+/// input stream was reached before anything could be read.
+pub(crate) fn read_line<R: io::BufRead>(read: &mut R) -> io::Result<String> {
+    let mut s = String::new();
+    match read.read_line(&mut s)? {
+        0 => Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "reached the end of the input stream",
+        )),
+        _ => Ok(s),
+    }
+}
+
+/// Returns the amount followed by the noun, in the plural form if needed (e.g. `2 items`).
+pub(crate) fn plural(count: usize, noun: &str) -> String {
+    match count {
+        1 => format!("{count} {noun}"),
+        _ => format!("{count} {noun}s"),
+    }
+}
+
+/// Prompts the promptable once with the provided line as input, without rendering anything.
+///
+/// This is used to interpret a value that doesn't come from the user input stream, with the same
+/// rules as the promptable.
+pub(crate) fn prompt_from_str<P: Promptable>(
+    prompt: &mut P, input: &str, fmt: &P::FmtRules,
+) -> io::Result<ControlFlow<P::Output, Rejection>> {
+    let input = format!("{input}\n");
+    prompt.prompt_once(input.as_bytes(), io::sink(), fmt)
+}
+
+/// Reads a line from the input stream, asynchronously.
+///
+/// This is the asynchronous version of [`read_line`].
+#[cfg(feature = "async")]
+pub(crate) async fn read_line_async<R>(read: &mut R) -> io::Result<String>
+where
+    R: tokio::io::AsyncBufRead + Unpin,
+{
+    use tokio::io::AsyncBufReadExt as _;
+    let mut s = String::new();
+    match read.read_line(&mut s).await? {
+        0 => Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "reached the end of the input stream",
+        )),
+        _ => Ok(s),
+    }
+}
+
+/// Writes the whole buffer into the output stream and flushes it, asynchronously.
+#[cfg(feature = "async")]
+pub(crate) async fn write_all_async<W>(write: &mut W, buf: &[u8]) -> io::Result<()>
+where
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    use tokio::io::AsyncWriteExt as _;
+    write.write_all(buf).await?;
+    write.flush().await
+}
@@ -0,0 +1,470 @@
+use std::{borrow::Cow, io, marker::PhantomData, ops::ControlFlow};
+
+use crate::{
+    Promptable, Rejection,
+    format::rules::{ExpandedSelectedFmtRules, SelectedFmtRules},
+};
+
+use super::selected::SelectList;
+
+/// Promptable type for multiple selectable inputs.
+///
+/// See the [`multi_selected()`] function for more information.
+#[derive(Clone)]
+pub struct MultiSelected<'a, 'fmt, T> {
+    list: SelectList<'a, T>,
+    min: usize,
+    max: usize,
+    _marker: PhantomData<&'fmt ()>,
+}
+
+impl<T> MultiSelected<'_, '_, T> {
+    /// Gives default items to the prompt, selected when the user enters an empty input.
+    ///
+    /// The indices are the positions of the items in the list provided to the
+    /// [`multi_selected()`] function, starting from 0. The default items are displayed right after
+    /// the message by their number in the list, with the
+    /// [`default_surrounds`](crate::format::FmtRule::default_surrounds) and
+    /// [`default_label`](crate::format::FmtRule::default_label) format rules.
+    ///
+    /// An index given more than once is only selected once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds of the list, or if there are more default items than
+    /// the maximum.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ineed::prelude::*;
+    /// let features = ineed::multi_selected("Features", [("async", 1), ("derive", 2), ("json", 3)])
+    ///   .default([0, 2])
+    ///   .prompt()
+    ///   .unwrap();
+    /// ```
+    ///
+    /// The above example will show something similar to this:
+    /// ```txt
+    /// [1] - async
+    /// [2] - derive
+    /// [3] - json
+    /// - Features [default: 1,3]
+    /// >
+    /// ```
+    pub fn default<I: IntoIterator<Item = usize>>(mut self, indices: I) -> Self {
+        let mut unique = Vec::new();
+        for index in indices {
+            if !unique.contains(&index) {
+                unique.push(index);
+            }
+        }
+        let count = unique.len();
+        assert!(
+            count <= self.max,
+            "the default items are {count} but the maximum is {}",
+            self.max
+        );
+        self.list.set_defaults(unique);
+        self
+    }
+
+    /// Sets the minimum amount of items the user must select.
+    ///
+    /// By default, the user can select no item at all.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the minimum is greater than the amount of items in the list, or than the maximum.
+    pub fn min(mut self, min: usize) -> Self {
+        let len = self.list.len();
+        assert!(
+            min <= len,
+            "the minimum is {min} but the list contains {len} items"
+        );
+        assert!(
+            min <= self.max,
+            "the minimum is {min} but the maximum is {}",
+            self.max
+        );
+        self.min = min;
+        self
+    }
+
+    /// Sets the maximum amount of items the user can select.
+    ///
+    /// By default, the user can select all the items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the maximum is less than the minimum, or than the amount of default items.
+    pub fn max(mut self, max: usize) -> Self {
+        assert!(
+            max >= self.min,
+            "the maximum is {max} but the minimum is {}",
+            self.min
+        );
+        let count = self.list.defaults.len();
+        assert!(
+            max >= count,
+            "the maximum is {max} but the default items are {count}"
+        );
+        self.max = max;
+        self
+    }
+
+    fn check_empty(&self) -> io::Result<()> {
+        if self.list.len() == 0 {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the list of the selectable items is empty",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Parses the input into the selected values.
+fn parse<T>(
+    list: &mut SelectList<'_, T>, input: &str, fmt: &ExpandedSelectedFmtRules<'_>, min: usize,
+    max: usize,
+) -> ControlFlow<Vec<T>, Rejection> {
+    match parse_indices(input, list.len(), &list.defaults, min, max) {
+        Ok(indices) => ControlFlow::Break(
+            indices
+                .into_iter()
+                .filter_map(|i| list.values[i].take())
+                .collect(),
+        ),
+        Err(reason) => ControlFlow::Continue(Rejection::new(reason, fmt)),
+    }
+}
+
+/// Parses the input into the 0-based indices of the selected items.
+fn parse_indices(
+    input: &str, len: usize, defaults: &[usize], min: usize, max: usize,
+) -> Result<Vec<usize>, String> {
+    let input = input.trim();
+    let mut indices = Vec::new();
+
+    if input.is_empty() {
+        indices.extend_from_slice(defaults);
+    } else if input.eq_ignore_ascii_case("all") {
+        indices.extend(0..len);
+    } else {
+        for part in input.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            let parse = |s: &str| match s.trim().parse::<usize>() {
+                Ok(i) if i >= 1 && i <= len => Ok(i - 1),
+                Ok(i) => Err(format!(
+                    "{i} is out of range, choose numbers between 1 and {len}"
+                )),
+                Err(_) => Err(format!("\"{part}\" isn't a number or a range of numbers")),
+            };
+
+            let (start, end) = match part.split_once('-') {
+                Some((start, end)) => (parse(start)?, parse(end)?),
+                None => (parse(part)?, parse(part)?),
+            };
+            if start > end {
+                return Err(format!("the range \"{part}\" is empty"));
+            }
+
+            for i in start..=end {
+                if indices.contains(&i) {
+                    return Err(format!("{} is selected more than once", i + 1));
+                }
+                indices.push(i);
+            }
+        }
+    }
+
+    match indices.len() {
+        n if n < min => Err(format!("select at least {}", super::plural(min, "item"))),
+        n if n > max => Err(format!("select at most {}", super::plural(max, "item"))),
+        _ => Ok(indices),
+    }
+}
+
+impl<'fmt, T> Promptable for MultiSelected<'_, 'fmt, T> {
+    type Output = Vec<T>;
+    type FmtRules = SelectedFmtRules<'fmt>;
+
+    fn prompt_once<R, W>(
+        &mut self, read: R, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: io::BufRead,
+        W: io::Write,
+    {
+        self.check_empty()?;
+        let (min, max) = (self.min, self.max);
+        self.list
+            .prompt_once_with(read, write, fmt, |list, input, fmt| {
+                parse(list, input, fmt, min, max)
+            })
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(nightly, doc(cfg(feature = "async")))]
+impl<T> crate::AsyncPromptable for MultiSelected<'_, '_, T> {
+    async fn prompt_once_async<R, W>(
+        &mut self, read: R, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        self.check_empty()?;
+        let (min, max) = (self.min, self.max);
+        self.list
+            .prompt_once_with_async(read, write, fmt, |list, input, fmt| {
+                parse(list, input, fmt, min, max)
+            })
+            .await
+    }
+}
+
+/// Returns a type that prompts the user several selectable values.
+///
+/// The values are presented as an indexed list, like with the [`selected()`](crate::selected)
+/// function, and with the same format rules. The user selects the items by entering their indices
+/// separated by commas, ranges of indices, or `all` to select all the items. For example,
+/// `1,3,5-7` selects the items 1, 3, 5, 6 and 7. The output is the values bound to these items,
+/// in the order they were entered.
+///
+/// The input is rejected if an index is out of range, if an item is selected more than once, or
+/// if the amount of selected items is out of the bounds given with the [`MultiSelected::min`] and
+/// [`MultiSelected::max`] methods.
+///
+/// Like the [`selected_list()`](crate::selected_list) function, the list can be any iterator
+/// of labels and values. If it is empty, prompting it returns an error of kind
+/// [`InvalidInput`](io::ErrorKind::InvalidInput).
+///
+/// # Example
+///
+/// ```no_run
+/// # use ineed::prelude::*;
+/// let toppings = ineed::multi_selected(
+///   "Your toppings",
+///   [("Cheese", 1), ("Ham", 2), ("Mushrooms", 3), ("Olives", 4)],
+/// )
+///   .min(1)
+///   .max(3)
+///   .prompt()
+///   .unwrap();
+/// ```
+///
+/// The above example will show something similar to this:
+/// ```txt
+/// [1] - Cheese
+/// [2] - Ham
+/// [3] - Mushrooms
+/// [4] - Olives
+/// - Your toppings
+/// >
+/// ```
+pub fn multi_selected<'a, 'fmt, I, L, T>(title: &'a str, list: I) -> MultiSelected<'a, 'fmt, T>
+where
+    I: IntoIterator<Item = (L, T)>,
+    L: Into<Cow<'a, str>>,
+{
+    MultiSelected {
+        list: SelectList::new(title, list),
+        min: 0,
+        max: usize::MAX,
+        _marker: PhantomData,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn list() -> [(&'static str, i32); 7] {
+        [
+            ("a", 1),
+            ("b", 2),
+            ("c", 3),
+            ("d", 4),
+            ("e", 5),
+            ("f", 6),
+            ("g", 7),
+        ]
+    }
+
+    #[test]
+    fn indices_and_ranges() -> anyhow::Result<()> {
+        let res = crate::multi_selected("", list())
+            .prompt_with("1,3,5-7\n".as_bytes(), std::io::empty())?;
+        assert_eq!(res, [1, 3, 5, 6, 7]);
+
+        let res = crate::multi_selected("", list())
+            .prompt_with(" 4 , 2-3 ,\n".as_bytes(), std::io::empty())?;
+        assert_eq!(res, [4, 2, 3]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn all() -> anyhow::Result<()> {
+        let res =
+            crate::multi_selected("", list()).prompt_with("ALL\n".as_bytes(), std::io::empty())?;
+        assert_eq!(res, [1, 2, 3, 4, 5, 6, 7]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejections() -> anyhow::Result<()> {
+        let input = "1,8\n2,1-3\nfoo\n3-2\n1,2\n".as_bytes();
+        let mut output = Vec::new();
+        let res = crate::multi_selected("Items", [("a", 'a'), ("b", 'b'), ("c", 'c')])
+            .prompt_with(input, &mut output)?;
+        assert_eq!(res, ['a', 'b']);
+        assert_eq!(
+            String::from_utf8(output)?,
+            "[1] - a\n[2] - b\n[3] - c\n- Items\n> \
+             ! 8 is out of range, choose numbers between 1 and 3\n> \
+             ! 2 is selected more than once\n> \
+             ! \"foo\" isn't a number or a range of numbers\n> \
+             ! the range \"3-2\" is empty\n> "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn min_max() -> anyhow::Result<()> {
+        let input = "\n1\n1-4\n1-3\n".as_bytes();
+        let mut output = Vec::new();
+        let res = crate::multi_selected("", list())
+            .min(2)
+            .max(3)
+            .fmt(crate::fmt().input_prefix(""))
+            .prompt_with(input, &mut output)?;
+        assert_eq!(res, [1, 2, 3]);
+        assert!(String::from_utf8(output)?.ends_with(
+            "! select at least 2 items\n\
+             ! select at least 2 items\n\
+             ! select at most 3 items\n"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn singular_bounds() -> anyhow::Result<()> {
+        let input = "\n1,2\n1\n".as_bytes();
+        let mut output = Vec::new();
+        crate::multi_selected("", list())
+            .min(1)
+            .max(1)
+            .fmt(crate::fmt().input_prefix(""))
+            .prompt_with(input, &mut output)?;
+        assert!(
+            String::from_utf8(output)?
+                .ends_with("! select at least 1 item\n! select at most 1 item\n")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic = "the maximum is 1 but the minimum is 3"]
+    fn max_below_min() {
+        let _ = crate::multi_selected("", list()).min(3).max(1);
+    }
+
+    #[test]
+    #[should_panic = "the minimum is 3 but the maximum is 1"]
+    fn min_above_max() {
+        let _ = crate::multi_selected("", list()).max(1).min(3);
+    }
+
+    #[test]
+    #[should_panic = "the maximum is 2 but the default items are 3"]
+    fn max_below_defaults() {
+        let _ = crate::multi_selected("", list()).default([0, 1, 2]).max(2);
+    }
+
+    #[test]
+    #[should_panic = "the default items are 3 but the maximum is 2"]
+    fn defaults_above_max() {
+        let _ = crate::multi_selected("", list())
+            .max(2)
+            .default([0, 1, 2, 1]);
+    }
+
+    #[test]
+    fn empty_selection() -> anyhow::Result<()> {
+        let res =
+            crate::multi_selected("", list()).prompt_with("\n".as_bytes(), std::io::empty())?;
+        assert!(res.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn defaults() -> anyhow::Result<()> {
+        let mut output = Vec::new();
+        let res = crate::multi_selected("Items", [("a", 'a'), ("b", 'b'), ("c", 'c')])
+            .default([2, 0])
+            .prompt_with("\n".as_bytes(), &mut output)?;
+        assert_eq!(res, ['c', 'a']);
+        assert_eq!(
+            String::from_utf8(output)?,
+            "[1] - a\n[2] - b\n[3] - c\n- Items [default: 3,1]\n> "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn duplicate_defaults() -> anyhow::Result<()> {
+        let mut output = Vec::new();
+        let res = crate::multi_selected("Items", [("a", 'a'), ("b", 'b'), ("c", 'c')])
+            .default([2, 0, 2])
+            .prompt_with("\n".as_bytes(), &mut output)?;
+        assert_eq!(res, ['c', 'a']);
+        assert!(String::from_utf8(output)?.ends_with("- Items [default: 3,1]\n> "));
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic = "the default index is 3 but the list contains 3 items"]
+    fn default_out_of_bounds() {
+        let _ = crate::multi_selected("", [("a", 1), ("b", 2), ("c", 3)]).default([0, 3]);
+    }
+
+    #[test]
+    fn empty_list() {
+        let res = crate::multi_selected::<_, &str, i32>("", [])
+            .prompt_with("all\n".as_bytes(), std::io::empty());
+        assert!(matches!(res, Err(e) if e.kind() == std::io::ErrorKind::InvalidInput));
+    }
+
+    #[test]
+    fn eof_input() {
+        let res =
+            crate::multi_selected("", list()).prompt_with("1,9\n".as_bytes(), std::io::empty());
+        assert!(matches!(res, Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_input() -> anyhow::Result<()> {
+        let (mut input, read) = tokio::io::duplex(1024);
+        tokio::io::AsyncWriteExt::write_all(&mut input, b"0\n2-3\n").await?;
+        drop(input);
+
+        let res = crate::multi_selected("", list())
+            .prompt_with_async(tokio::io::BufReader::new(read), tokio::io::sink())
+            .await?;
+        assert_eq!(res, [2, 3]);
+
+        Ok(())
+    }
+}
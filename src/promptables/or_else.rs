@@ -0,0 +1,324 @@
+use std::{
+    io::{self, BufRead, Read},
+    ops::ControlFlow,
+};
+
+use crate::{Promptable, Rejection, promptables::prompt_from_str};
+
+/// The output of two alternative promptables.
+///
+/// This is returned by the [`OrElse`] promptable type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Either<L, R> {
+    /// The output of the first promptable.
+    Left(L),
+    /// The output of the second promptable.
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    /// Returns the output of the first promptable, if any.
+    pub fn left(self) -> Option<L> {
+        match self {
+            Self::Left(l) => Some(l),
+            Self::Right(_) => None,
+        }
+    }
+
+    /// Returns the output of the second promptable, if any.
+    pub fn right(self) -> Option<R> {
+        match self {
+            Self::Left(_) => None,
+            Self::Right(r) => Some(r),
+        }
+    }
+}
+
+/// Wrapper for promptable types to interpret a rejected input with another promptable.
+///
+/// See the [`Promptable::or_else()`] method for more information.
+#[derive(Clone)]
+pub struct OrElse<A, B> {
+    pub(crate) first: A,
+    pub(crate) other: B,
+}
+
+impl<A, B> Promptable for OrElse<A, B>
+where
+    A: Promptable,
+    B: Promptable,
+    <B as Promptable>::FmtRules: From<<A as Promptable>::FmtRules>,
+{
+    type Output = Either<<A as Promptable>::Output, <B as Promptable>::Output>;
+    type FmtRules = <A as Promptable>::FmtRules;
+
+    fn prompt_once<R, W>(
+        &mut self, read: R, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: io::BufRead,
+        W: io::Write,
+    {
+        let mut read = Recorder::new(read);
+        let flow = self.first.prompt_once(&mut read, write, fmt)?;
+        self.or_else(flow, read.line(), fmt)
+    }
+}
+
+impl<A, B> OrElse<A, B>
+where
+    A: Promptable,
+    B: Promptable,
+    <B as Promptable>::FmtRules: From<<A as Promptable>::FmtRules>,
+{
+    /// Interprets the line rejected by the first promptable with the other one.
+    ///
+    /// If both reject it, the returned rejection contains both reasons.
+    fn or_else(
+        &mut self, flow: ControlFlow<<A as Promptable>::Output, Rejection>, line: &str,
+        fmt: &<A as Promptable>::FmtRules,
+    ) -> io::Result<ControlFlow<<Self as Promptable>::Output, Rejection>> {
+        let rejection = match flow {
+            ControlFlow::Break(out) => return Ok(ControlFlow::Break(Either::Left(out))),
+            ControlFlow::Continue(rejection) => rejection,
+        };
+
+        let other_fmt = From::from(fmt.clone());
+        Ok(match prompt_from_str(&mut self.other, line, &other_fmt)? {
+            ControlFlow::Break(out) => ControlFlow::Break(Either::Right(out)),
+            ControlFlow::Continue(other) => ControlFlow::Continue(Rejection::new(
+                format_args!("{}, or {}", rejection.reason(), other.reason()),
+                fmt,
+            )),
+        })
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(nightly, doc(cfg(feature = "async")))]
+impl<A, B> crate::AsyncPromptable for OrElse<A, B>
+where
+    A: crate::AsyncPromptable,
+    B: Promptable,
+    <B as Promptable>::FmtRules: From<<A as Promptable>::FmtRules>,
+{
+    async fn prompt_once_async<R, W>(
+        &mut self, read: R, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        let mut read = Recorder::new(read);
+        let flow = self.first.prompt_once_async(&mut read, write, fmt).await?;
+        self.or_else(flow, read.line(), fmt)
+    }
+}
+
+/// Input stream wrapper that records the last line read from it.
+struct Recorder<R> {
+    inner: R,
+    line: Vec<u8>,
+    /// The last buffer filled by the inner stream, used by the asynchronous version.
+    #[cfg(feature = "async")]
+    filled: Vec<u8>,
+}
+
+impl<R> Recorder<R> {
+    fn new(inner: R) -> Self {
+        Self {
+            inner,
+            line: Vec::new(),
+            #[cfg(feature = "async")]
+            filled: Vec::new(),
+        }
+    }
+
+    fn record(&mut self, bytes: &[u8]) {
+        if self.line.ends_with(b"\n") {
+            self.line.clear();
+        }
+        self.line.extend_from_slice(bytes);
+    }
+
+    /// Returns the last line read, without its line break.
+    fn line(&self) -> &str {
+        let line = str::from_utf8(&self.line).unwrap_or_default();
+        line.strip_suffix('\n')
+            .map_or(line, |line| line.strip_suffix('\r').unwrap_or(line))
+    }
+}
+
+impl<R: Read> Read for Recorder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.record(&buf[..n]);
+        Ok(n)
+    }
+}
+
+impl<R: BufRead> BufRead for Recorder<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        if let Ok(buf) = self.inner.fill_buf() {
+            let consumed = buf[..amt.min(buf.len())].to_vec();
+            self.record(&consumed);
+        }
+        self.inner.consume(amt);
+    }
+}
+
+#[cfg(feature = "async")]
+impl<R> tokio::io::AsyncRead for Recorder<R>
+where
+    R: tokio::io::AsyncRead + Unpin,
+{
+    fn poll_read(
+        self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> std::task::Poll<io::Result<()>> {
+        let this = self.get_mut();
+        let start = buf.filled().len();
+        let poll = std::pin::Pin::new(&mut this.inner).poll_read(cx, buf);
+        if let std::task::Poll::Ready(Ok(())) = poll {
+            this.record(&buf.filled()[start..]);
+        }
+        poll
+    }
+}
+
+#[cfg(feature = "async")]
+impl<R> tokio::io::AsyncBufRead for Recorder<R>
+where
+    R: tokio::io::AsyncBufRead + Unpin,
+{
+    fn poll_fill_buf(
+        self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<io::Result<&[u8]>> {
+        let this = self.get_mut();
+        let poll = std::pin::Pin::new(&mut this.inner).poll_fill_buf(cx);
+        if let std::task::Poll::Ready(Ok(buf)) = &poll {
+            this.filled.clear();
+            this.filled.extend_from_slice(buf);
+        }
+        poll
+    }
+
+    fn consume(self: std::pin::Pin<&mut Self>, amt: usize) {
+        let this = self.get_mut();
+        let consumed: Vec<u8> = this.filled.drain(..amt.min(this.filled.len())).collect();
+        this.record(&consumed);
+        std::pin::Pin::new(&mut this.inner).consume(amt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Either;
+    use crate::prelude::*;
+
+    #[test]
+    fn index_or_name() -> anyhow::Result<()> {
+        let input = "3\nfoo\n".as_bytes();
+        let mut output = Vec::new();
+        let prompt = || crate::written::<u32>("User").or_else(crate::written::<String>(""));
+
+        let res = prompt().prompt_with(input, &mut output)?;
+        assert_eq!(res, Either::Left(3));
+        assert_eq!(String::from_utf8(output)?, "- User\n> ");
+
+        let mut output = Vec::new();
+        let res = prompt().prompt_with("foo\n".as_bytes(), &mut output)?;
+        assert_eq!(res, Either::Right("foo".to_owned()));
+        assert_eq!(String::from_utf8(output)?, "- User\n> ");
+
+        Ok(())
+    }
+
+    #[test]
+    fn both_rejected() -> anyhow::Result<()> {
+        let input = "foo\nmaybe\n12\n".as_bytes();
+        let mut output = Vec::new();
+        let res = crate::written::<u8>("Level")
+            .or_else(crate::bool(""))
+            .fmt(crate::fmt().inline())
+            .prompt_with(input, &mut output)?;
+
+        assert_eq!(res, Either::Left(12));
+        assert_eq!(
+            String::from_utf8(output)?,
+            "Level: ! invalid digit found in string, or please answer with yes or no\n\
+            Level: ! invalid digit found in string, or please answer with yes or no\n\
+            Level: "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn selected_or_written() -> anyhow::Result<()> {
+        let input = "n\nApache\n".as_bytes();
+        let res = crate::selected("License", [("MIT", 1), ("GPL", 2), ("BSD", 3)])
+            .map(Some)
+            .or_else(crate::written::<String>(""))
+            .fmt(crate::fmt().page_size(2))
+            .prompt_with(input, std::io::empty())?;
+
+        assert_eq!(res, Either::Right("Apache".to_owned()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn written_or_selected() -> anyhow::Result<()> {
+        let input = "GPL\n2\n".as_bytes();
+        let mut output = Vec::new();
+        let prompt = || {
+            crate::written::<u8>("License ID")
+                .or_else(crate::selected("", [("MIT", "MIT"), ("GPL", "GPL-3.0")]))
+                .fmt(crate::fmt().inline().err_prefix("error: "))
+        };
+
+        let res = prompt().prompt_with(input, &mut output)?;
+        assert_eq!(res, Either::Right("GPL-3.0"));
+        assert_eq!(String::from_utf8(output)?, "License ID: ");
+
+        let mut output = Vec::new();
+        let res = prompt().prompt_with("foo\n7\n".as_bytes(), &mut output)?;
+        assert_eq!(res, Either::Left(7));
+        assert_eq!(
+            String::from_utf8(output)?,
+            "License ID: error: invalid digit found in string, or choose a number between 1 and 2\n\
+            License ID: "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn eof_input() {
+        let res = crate::written::<u8>("")
+            .or_else(crate::bool(""))
+            .prompt_with("foo\n".as_bytes(), std::io::empty());
+        assert_eq!(res.unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_index_or_name() -> anyhow::Result<()> {
+        let (mut input, read) = tokio::io::duplex(1024);
+        tokio::io::AsyncWriteExt::write_all(&mut input, b"foo\r\n").await?;
+        drop(input);
+
+        let res = crate::written::<u32>("")
+            .or_else(crate::written::<String>(""))
+            .prompt_with_async(tokio::io::BufReader::new(read), tokio::io::sink())
+            .await?;
+        assert_eq!(res, Either::Right("foo".to_owned()));
+
+        Ok(())
+    }
+}
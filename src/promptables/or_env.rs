@@ -0,0 +1,197 @@
+use std::{ffi::OsString, io, ops::ControlFlow};
+
+use crate::{Promptable, Rejection};
+
+/// Wrapper for promptable types to take their value from an environment variable, if it's set.
+///
+/// See the [`Promptable::or_env()`] method for more information.
+#[derive(Clone)]
+pub struct OrEnv<P> {
+    pub(crate) prompt: P,
+    pub(crate) var: String,
+    /// The function reading the environment, i.e. [`std::env::var_os`] outside of the tests.
+    pub(crate) lookup: fn(&str) -> Option<OsString>,
+}
+
+impl<P: Promptable> OrEnv<P> {
+    /// Interprets the value of the environment variable, or returns `None` if it's not set.
+    fn env_value(&mut self, fmt: &P::FmtRules) -> io::Result<Option<P::Output>> {
+        let value = match (self.lookup)(&self.var).map(OsString::into_string) {
+            Some(Ok(value)) => value,
+            None => return Ok(None),
+            Some(Err(_)) => return Err(self.invalid("the value isn't valid unicode")),
+        };
+
+        match crate::prompt_from_str(&mut self.prompt, &value, fmt)? {
+            ControlFlow::Break(out) => Ok(Some(out)),
+            ControlFlow::Continue(rejection) => Err(self.invalid(rejection)),
+        }
+    }
+
+    fn invalid<R: std::fmt::Display>(&self, reason: R) -> io::Error {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "invalid value for the environment variable \"{}\": {reason}",
+                self.var
+            ),
+        )
+    }
+}
+
+impl<P: Promptable> Promptable for OrEnv<P> {
+    type Output = <P as Promptable>::Output;
+    type FmtRules = <P as Promptable>::FmtRules;
+
+    fn prompt_once<R, W>(
+        &mut self, read: R, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: io::BufRead,
+        W: io::Write,
+    {
+        match self.env_value(fmt)? {
+            Some(out) => Ok(ControlFlow::Break(out)),
+            None => self.prompt.prompt_once(read, write, fmt),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(nightly, doc(cfg(feature = "async")))]
+impl<P> crate::AsyncPromptable for OrEnv<P>
+where
+    P: crate::AsyncPromptable,
+{
+    async fn prompt_once_async<R, W>(
+        &mut self, read: R, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        match self.env_value(fmt)? {
+            Some(out) => Ok(ControlFlow::Break(out)),
+            None => self.prompt.prompt_once_async(read, write, fmt).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsString;
+
+    use super::OrEnv;
+    use crate::prelude::*;
+
+    /// Wraps the promptable with a fake environment, so the tests don't mutate the process
+    /// environment.
+    fn or_env<P: crate::Promptable>(
+        prompt: P, var: &str, lookup: fn(&str) -> Option<OsString>,
+    ) -> OrEnv<P> {
+        OrEnv {
+            prompt,
+            var: var.to_owned(),
+            lookup,
+        }
+    }
+
+    #[test]
+    fn value_from_env() -> anyhow::Result<()> {
+        let mut output = Vec::new();
+        let port = or_env(crate::written::<u16>("Port"), "PORT", |var| {
+            (var == "PORT").then(|| "8080".into())
+        })
+        .prompt_with(std::io::empty(), &mut output)?;
+        assert_eq!(port, 8080);
+        assert!(output.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_var() -> anyhow::Result<()> {
+        let port = crate::written::<u16>("Port")
+            .or_env("INEED_TEST_OR_ENV_MISSING")
+            .prompt_with("80\n".as_bytes(), std::io::empty())?;
+        assert_eq!(port, 80);
+
+        let port = or_env(crate::written::<u16>("Port"), "PORT", |_| None)
+            .prompt_with("80\n".as_bytes(), std::io::empty())?;
+        assert_eq!(port, 80);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_value() {
+        let res = or_env(crate::bool("Proceed?"), "PROCEED", |_| Some("maybe".into()))
+            .prompt_with("yes\n".as_bytes(), std::io::empty());
+        assert!(matches!(
+            res,
+            Err(e) if e.kind() == std::io::ErrorKind::InvalidData
+                && e.to_string() == "invalid value for the environment variable \
+                    \"PROCEED\": please answer with yes or no"
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_unicode_value() {
+        use std::os::unix::ffi::OsStringExt as _;
+
+        let res = or_env(crate::written::<String>("Name"), "NAME", |_| {
+            Some(OsString::from_vec(vec![0xff]))
+        })
+        .prompt_with("foo\n".as_bytes(), std::io::empty());
+        assert!(matches!(
+            res,
+            Err(e) if e.kind() == std::io::ErrorKind::InvalidData
+                && e.to_string() == "invalid value for the environment variable \
+                    \"NAME\": the value isn't valid unicode"
+        ));
+    }
+
+    #[test]
+    fn selected_label_or_index() -> anyhow::Result<()> {
+        let prompt = |lookup| {
+            or_env(
+                crate::selected("License", [("MIT", 1), ("GPL", 2)]),
+                "L",
+                lookup,
+            )
+        };
+
+        let res = prompt(|_| Some("GPL".into())).prompt_with(std::io::empty(), std::io::empty())?;
+        assert_eq!(res, 2);
+
+        let res = prompt(|_| Some("1".into())).prompt_with(std::io::empty(), std::io::empty())?;
+        assert_eq!(res, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn in_chain() -> anyhow::Result<()> {
+        let (name, age) = or_env(crate::written::<String>("Name"), "NAME", |_| {
+            Some("foo".into())
+        })
+        .then(crate::written::<u8>("Age"))
+        .prompt_with("32\n".as_bytes(), std::io::empty())?;
+        assert_eq!(name, "foo");
+        assert_eq!(age, 32);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_value_from_env() -> anyhow::Result<()> {
+        let res = or_env(crate::written::<u8>(""), "LEVEL", |_| Some("12".into()))
+            .prompt_with_async(tokio::io::empty(), tokio::io::sink())
+            .await?;
+        assert_eq!(res, 12);
+
+        Ok(())
+    }
+}
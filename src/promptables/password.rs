@@ -1,12 +1,13 @@
 use std::{io, ops::ControlFlow};
 
-use crate::{Promptable, WrittenFmtRules, WrittenInner};
+use crate::{EMPTY_INPUT, Promptable, Rejection, WrittenFmtRules, WrittenInner};
 
 /// Promptable type for passwords.
 ///
 /// See the [`password()`] for more information.
 #[cfg(feature = "rpassword")]
 #[cfg_attr(nightly, doc(cfg(feature = "rpassword")))]
+#[derive(Clone)]
 pub struct Password<'a, 'fmt> {
     inner: WrittenInner<'a, 'fmt>,
 }
@@ -32,7 +33,7 @@ impl<'fmt> Promptable for Password<'_, 'fmt> {
 
     fn prompt_once<R, W>(
         &mut self, read: R, write: W, fmt: &Self::FmtRules,
-    ) -> io::Result<ControlFlow<Self::Output>>
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
     where
         R: io::BufRead,
         W: io::Write,
@@ -40,7 +41,7 @@ impl<'fmt> Promptable for Password<'_, 'fmt> {
         self.inner
             .prompt_with(read, write, fmt, |_| rpassword::read_password())
             .map(|s| match s.is_empty() {
-                true => ControlFlow::Continue(()),
+                true => ControlFlow::Continue(Rejection::new(EMPTY_INPUT, fmt)),
                 false => ControlFlow::Break(s),
             })
     }
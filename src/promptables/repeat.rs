@@ -0,0 +1,455 @@
+use std::{
+    io::{self, BufRead, Read},
+    marker::PhantomData,
+    mem,
+    ops::ControlFlow,
+};
+
+use crate::{Promptable, Rejection};
+
+/// Wrapper for promptable types to collect several answers to the same question.
+///
+/// See the [`Promptable::repeat()`] method for more information.
+pub struct Repeat<P: Promptable, C> {
+    pub(crate) prompt: P,
+    pub(crate) items: Vec<<P as Promptable>::Output>,
+    pub(crate) sentinel: String,
+    pub(crate) min: usize,
+    pub(crate) max: Option<usize>,
+    pub(crate) _marker: PhantomData<fn() -> C>,
+}
+
+impl<P, C> Clone for Repeat<P, C>
+where
+    P: Promptable + Clone,
+    <P as Promptable>::Output: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            prompt: self.prompt.clone(),
+            items: self.items.clone(),
+            sentinel: self.sentinel.clone(),
+            min: self.min,
+            max: self.max,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<P: Promptable, C> Repeat<P, C> {
+    /// Sets the line that ends the collection, instead of an empty line.
+    ///
+    /// The line is compared to the user input without the surrounding whitespaces.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ineed::prelude::*;
+    /// let notes: Vec<String> = ineed::written::<String>("Notes (enter \"done\" to finish)")
+    ///   .repeat()
+    ///   .sentinel("done")
+    ///   .prompt()
+    ///   .unwrap();
+    /// ```
+    pub fn sentinel<S: Into<String>>(mut self, sentinel: S) -> Self {
+        self.sentinel = sentinel.into();
+        self
+    }
+
+    /// Sets the minimum amount of answers.
+    ///
+    /// If the user ends the collection too early, the prompt continues.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the minimum is greater than the maximum.
+    pub fn min(mut self, min: usize) -> Self {
+        if let Some(max) = self.max {
+            assert!(min <= max, "the minimum is {min} but the maximum is {max}");
+        }
+        self.min = min;
+        self
+    }
+
+    /// Sets the maximum amount of answers.
+    ///
+    /// The collection ends on its own once the user entered this amount of answers.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the maximum is less than the minimum.
+    pub fn max(mut self, max: usize) -> Self {
+        assert!(
+            max >= self.min,
+            "the maximum is {max} but the minimum is {}",
+            self.min
+        );
+        self.max = Some(max);
+        self
+    }
+}
+
+impl<P, C> Repeat<P, C>
+where
+    P: Promptable,
+    C: FromIterator<<P as Promptable>::Output>,
+{
+    /// Handles the result of a single answer.
+    ///
+    /// `idle` tells whether the answer was given without reading any input, e.g. from an
+    /// environment variable. Returns `None` if the next answer must be prompted.
+    fn next(
+        &mut self, flow: io::Result<ControlFlow<<P as Promptable>::Output, Rejection>>,
+        ended: bool, idle: bool, fmt: &<P as Promptable>::FmtRules,
+    ) -> io::Result<Option<ControlFlow<C, Rejection>>> {
+        if ended {
+            return Ok(Some(if self.items.len() < self.min {
+                ControlFlow::Continue(Rejection::new(
+                    format_args!("enter at least {}", super::plural(self.min, "answer")),
+                    fmt,
+                ))
+            } else {
+                ControlFlow::Break(self.collect())
+            }));
+        }
+
+        match flow? {
+            ControlFlow::Break(item) => self.items.push(item),
+            ControlFlow::Continue(rejection) => return Ok(Some(ControlFlow::Continue(rejection))),
+        }
+
+        // The next answers wouldn't read any input either, so they would be collected forever.
+        let full = idle || self.max.is_some_and(|max| self.items.len() >= max);
+        Ok(full.then(|| ControlFlow::Break(self.collect())))
+    }
+
+    fn collect(&mut self) -> C {
+        mem::take(&mut self.items).into_iter().collect()
+    }
+}
+
+impl<P, C> Promptable for Repeat<P, C>
+where
+    P: Promptable,
+    C: FromIterator<<P as Promptable>::Output>,
+{
+    type Output = C;
+    type FmtRules = <P as Promptable>::FmtRules;
+
+    fn prompt_once<R, W>(
+        &mut self, read: R, mut write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: io::BufRead,
+        W: io::Write,
+    {
+        let mut read = LineReader::new(read, self.sentinel.clone());
+        loop {
+            let lines = read.lines;
+            let flow = self.prompt.prompt_once(&mut read, &mut write, fmt);
+            let (ended, idle) = (read.ended, read.lines == lines);
+            if let Some(flow) = self.next(flow, ended, idle, fmt)? {
+                return Ok(flow);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(nightly, doc(cfg(feature = "async")))]
+impl<P, C> crate::AsyncPromptable for Repeat<P, C>
+where
+    P: crate::AsyncPromptable,
+    C: FromIterator<<P as Promptable>::Output>,
+{
+    async fn prompt_once_async<R, W>(
+        &mut self, read: R, mut write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        let mut read = LineReader::new(read, self.sentinel.clone());
+        loop {
+            let lines = read.lines;
+            let flow = self
+                .prompt
+                .prompt_once_async(&mut read, &mut write, fmt)
+                .await;
+            let (ended, idle) = (read.ended, read.lines == lines);
+            if let Some(flow) = self.next(flow, ended, idle, fmt)? {
+                return Ok(flow);
+            }
+        }
+    }
+}
+
+/// Input stream wrapper that reads whole lines, and stops at the sentinel line.
+///
+/// Once the sentinel line is read, the stream behaves as if it reached its end.
+struct LineReader<R> {
+    inner: R,
+    sentinel: String,
+    line: Vec<u8>,
+    pos: usize,
+    ended: bool,
+    /// The amount of lines read from the inner stream.
+    lines: usize,
+    /// Whether the line is being read from the inner stream, used by the asynchronous version.
+    #[cfg(feature = "async")]
+    filling: bool,
+}
+
+impl<R> LineReader<R> {
+    fn new(inner: R, sentinel: String) -> Self {
+        Self {
+            inner,
+            sentinel,
+            line: Vec::new(),
+            pos: 0,
+            ended: false,
+            lines: 0,
+            #[cfg(feature = "async")]
+            filling: false,
+        }
+    }
+
+    /// Counts the line that was just read, and checks whether it's the sentinel line.
+    fn check_line(&mut self) {
+        self.lines += 1;
+        if !self.line.is_empty()
+            && str::from_utf8(&self.line).is_ok_and(|line| line.trim() == self.sentinel.trim())
+        {
+            self.ended = true;
+            self.line.clear();
+        }
+    }
+}
+
+impl<R: BufRead> Read for LineReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = {
+            let line = self.fill_buf()?;
+            let n = line.len().min(buf.len());
+            buf[..n].copy_from_slice(&line[..n]);
+            n
+        };
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl<R: BufRead> BufRead for LineReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if !self.ended && self.pos == self.line.len() {
+            self.line.clear();
+            self.pos = 0;
+            self.inner.read_until(b'\n', &mut self.line)?;
+            self.check_line();
+        }
+        Ok(&self.line[self.pos..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.line.len());
+    }
+}
+
+#[cfg(feature = "async")]
+impl<R> tokio::io::AsyncRead for LineReader<R>
+where
+    R: tokio::io::AsyncBufRead + Unpin,
+{
+    fn poll_read(
+        mut self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> std::task::Poll<io::Result<()>> {
+        use tokio::io::AsyncBufRead as _;
+
+        let n = {
+            let line = std::task::ready!(self.as_mut().poll_fill_buf(cx))?;
+            let n = line.len().min(buf.remaining());
+            buf.put_slice(&line[..n]);
+            n
+        };
+        self.consume(n);
+        std::task::Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<R> tokio::io::AsyncBufRead for LineReader<R>
+where
+    R: tokio::io::AsyncBufRead + Unpin,
+{
+    fn poll_fill_buf(
+        self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<io::Result<&[u8]>> {
+        let this = self.get_mut();
+        if !this.ended && !this.filling && this.pos == this.line.len() {
+            this.line.clear();
+            this.pos = 0;
+            this.filling = true;
+        }
+        while this.filling {
+            let buf = std::task::ready!(std::pin::Pin::new(&mut this.inner).poll_fill_buf(cx))?;
+            let n = match buf.iter().position(|b| *b == b'\n') {
+                Some(i) => {
+                    this.filling = false;
+                    i + 1
+                }
+                None if buf.is_empty() => {
+                    this.filling = false;
+                    0
+                }
+                None => buf.len(),
+            };
+            this.line.extend_from_slice(&buf[..n]);
+            std::pin::Pin::new(&mut this.inner).consume(n);
+            if !this.filling {
+                this.check_line();
+            }
+        }
+        std::task::Poll::Ready(Ok(&this.line[this.pos..]))
+    }
+
+    fn consume(self: std::pin::Pin<&mut Self>, amt: usize) {
+        let this = self.get_mut();
+        this.pos = (this.pos + amt).min(this.line.len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn until_empty_line() -> anyhow::Result<()> {
+        let input = "foo.txt\nbar/baz qux.txt\n\n".as_bytes();
+        let mut output = Vec::new();
+        let files: Vec<String> = crate::written::<String>("Files")
+            .repeat()
+            .prompt_with(input, &mut output)?;
+
+        assert_eq!(files, ["foo.txt", "bar/baz qux.txt"]);
+        assert_eq!(String::from_utf8(output)?, "- Files\n> > > ");
+
+        Ok(())
+    }
+
+    #[test]
+    fn sentinel() -> anyhow::Result<()> {
+        let input = "1\nfoo\n\n2\n done \n".as_bytes();
+        let mut output = Vec::new();
+        let res: std::collections::BTreeSet<i32> = crate::written::<i32>("Numbers")
+            .repeat()
+            .sentinel("done")
+            .fmt(crate::fmt().inline())
+            .prompt_with(input, &mut output)?;
+
+        assert_eq!(res, [1, 2].into());
+        assert_eq!(
+            String::from_utf8(output)?,
+            "Numbers: Numbers: ! invalid digit found in string\n\
+            Numbers: ! please enter a value\n\
+            Numbers: Numbers: "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn min_max() -> anyhow::Result<()> {
+        let input = "1\n\n2\n\n".as_bytes();
+        let mut output = Vec::new();
+        let res: Vec<i32> = crate::written::<i32>("")
+            .repeat()
+            .min(2)
+            .fmt(crate::fmt().msg_prefix("").input_prefix("> "))
+            .prompt_with(input, &mut output)?;
+        assert_eq!(res, [1, 2]);
+        assert_eq!(
+            String::from_utf8(output)?,
+            "\n> > ! enter at least 2 answers\n> > "
+        );
+
+        let input = "1\n2\n3\n".as_bytes();
+        let res: Vec<i32> = crate::written::<i32>("")
+            .repeat()
+            .max(2)
+            .prompt_with(input, std::io::empty())?;
+        assert_eq!(res, [1, 2]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn singular_min() -> anyhow::Result<()> {
+        let input = "\n1\n\n".as_bytes();
+        let mut output = Vec::new();
+        let res: Vec<i32> = crate::written::<i32>("")
+            .repeat()
+            .min(1)
+            .fmt(crate::fmt().msg_prefix(""))
+            .prompt_with(input, &mut output)?;
+        assert_eq!(res, [1]);
+        assert_eq!(
+            String::from_utf8(output)?,
+            "\n> ! enter at least 1 answer\n> > "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic = "the maximum is 1 but the minimum is 3"]
+    fn max_below_min() {
+        let _: super::Repeat<_, Vec<i32>> = crate::written::<i32>("").repeat().min(3).max(1);
+    }
+
+    #[test]
+    #[should_panic = "the minimum is 3 but the maximum is 1"]
+    fn min_above_max() {
+        let _: super::Repeat<_, Vec<i32>> = crate::written::<i32>("").repeat().max(1).min(3);
+    }
+
+    #[test]
+    fn answer_without_input() -> anyhow::Result<()> {
+        let answers = crate::answers::Answers::new().answer("port", "8080");
+        let mut output = Vec::new();
+        let res: Vec<u16> = crate::written::<u16>("Ports")
+            .key("port")
+            .answers(&answers)
+            .repeat()
+            .prompt_with("".as_bytes(), &mut output)?;
+
+        assert_eq!(res, [8080]);
+        assert!(output.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn eof_input() {
+        let res: std::io::Result<Vec<i32>> = crate::written::<i32>("")
+            .repeat()
+            .prompt_with("1\n2\n".as_bytes(), std::io::empty());
+        assert_eq!(res.unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_until_empty_line() -> anyhow::Result<()> {
+        let (mut input, read) = tokio::io::duplex(1024);
+        tokio::io::AsyncWriteExt::write_all(&mut input, b"1\nfoo\n2\n\n").await?;
+        drop(input);
+
+        let res: Vec<i32> = crate::written::<i32>("")
+            .repeat()
+            .prompt_with_async(tokio::io::BufReader::new(read), tokio::io::sink())
+            .await?;
+        assert_eq!(res, [1, 2]);
+
+        Ok(())
+    }
+}
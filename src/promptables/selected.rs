@@ -1,33 +1,122 @@
-use std::{io, marker::PhantomData, ops::ControlFlow};
+use std::{borrow::Cow, io, marker::PhantomData, ops::ControlFlow};
 
 use crate::{
-    Promptable,
-    format::{Partial as _, Position, rules::SelectedFmtRules},
+    Promptable, Rejection,
+    format::{
+        self, Partial as _, Position,
+        rules::{ExpandedSelectedFmtRules, SelectedFmtRules},
+    },
 };
 
-/// Promptable type for selectable inputs.
+/// The way the user input is matched against the labels of a selectable prompt.
 ///
-/// See the [`selected()`] function for more information.
-pub struct Selected<'a, 'fmt, const N: usize, T> {
+/// Whatever the mode is, the user can always select an item by its index in the list.
+///
+/// See the [`Selected::label_match`] method for more information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LabelMatch {
+    /// The items can only be selected by their index.
+    Disabled,
+    /// The input must be exactly the label of the item.
+    #[default]
+    Exact,
+    /// The input must be the label of the item, regardless of the case.
+    CaseInsensitive,
+    /// The input must be the beginning of the label of a single item, regardless of the case.
+    ///
+    /// If the input is the full label of an item, it is selected even if it's the beginning of
+    /// other labels. When the list is paginated, `n` and `p` navigate between the pages, unless
+    /// they're the full label of an item.
+    Prefix,
+}
+
+/// The state of a selectable prompt, shared by the selectable promptable types.
+#[derive(Clone)]
+pub(crate) struct SelectList<'a, T> {
     title: Option<&'a str>,
-    msgs: Option<[&'a str; N]>,
-    values: [Option<T>; N],
+    pub(crate) labels: Vec<Cow<'a, str>>,
+    pub(crate) values: Vec<Option<T>>,
+    pub(crate) defaults: Vec<usize>,
+    pub(crate) label_match: LabelMatch,
+    pub(crate) fuzzy: bool,
+    /// The indices of the displayed items, if the list was filtered by a search term.
+    filter: Option<Vec<usize>>,
+    /// The index of the displayed page, when the list is paginated.
+    page: usize,
     is_first_prompt: bool,
-    _marker: PhantomData<&'fmt ()>,
 }
 
-impl<'fmt, const N: usize, T> Promptable for Selected<'_, 'fmt, N, T> {
-    type Output = T;
-    type FmtRules = SelectedFmtRules<'fmt>;
-
-    fn prompt_once<R, W>(
-        &mut self, mut read: R, mut write: W, fmt: &Self::FmtRules,
-    ) -> io::Result<ControlFlow<Self::Output>>
+impl<'a, T> SelectList<'a, T> {
+    pub(crate) fn new<I, L>(title: &'a str, list: I) -> Self
     where
-        R: io::BufRead,
-        W: io::Write,
+        I: IntoIterator<Item = (L, T)>,
+        L: Into<Cow<'a, str>>,
     {
-        let fmt = fmt.expand();
+        let (labels, values) = list
+            .into_iter()
+            .map(|(label, value)| (label.into(), Some(value)))
+            .unzip();
+
+        Self {
+            title: Some(title),
+            labels,
+            values,
+            defaults: Vec::new(),
+            label_match: LabelMatch::default(),
+            fuzzy: false,
+            filter: None,
+            page: 0,
+            is_first_prompt: true,
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Returns the index in the whole list of the displayed item at the provided position.
+    fn visible_index(&self, i: usize) -> Option<usize> {
+        match &self.filter {
+            Some(filter) => filter.get(i).copied(),
+            None => (i < self.len()).then_some(i),
+        }
+    }
+
+    fn visible_len(&self) -> usize {
+        self.filter.as_ref().map_or(self.len(), Vec::len)
+    }
+
+    /// Returns the amount of pages of the displayed items.
+    fn pages(&self, page_size: usize) -> usize {
+        match page_size {
+            0 => 1,
+            size => self.visible_len().div_ceil(size).max(1),
+        }
+    }
+
+    /// Sets the default items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds of the list.
+    pub(crate) fn set_defaults<I: IntoIterator<Item = usize>>(&mut self, indices: I) {
+        let len = self.len();
+        self.defaults = indices
+            .into_iter()
+            .inspect(|index| {
+                assert!(
+                    *index < len,
+                    "the default index is {index} but the list contains {len} items"
+                )
+            })
+            .collect();
+    }
+
+    /// Writes the list and the title, if they must be displayed, and the input prefix.
+    #[allow(clippy::collapsible_if)]
+    fn write_prompt<W: io::Write>(
+        &mut self, mut write: W, fmt: &ExpandedSelectedFmtRules<'_>,
+    ) -> io::Result<()> {
         let (open, close) = fmt.list_surrounds;
 
         if fmt.list_msg_pos == Position::Top && self.is_first_prompt {
@@ -36,12 +125,32 @@ impl<'fmt, const N: usize, T> Promptable for Selected<'_, 'fmt, N, T> {
             } else {
                 self.title.take()
             } {
-                writeln!(write, "{}{}", fmt.msg_prefix, title)?;
+                self.write_msg(&mut write, title, fmt)?;
+                writeln!(write)?;
             }
         }
-        if let Some(list) = self.msgs.take() {
-            for (msg, i) in list.into_iter().zip(1..) {
-                writeln!(write, "{open}{i}{close}{msg}")?;
+        if self.is_first_prompt {
+            let pages = self.pages(fmt.page_size);
+            let (start, end) = match fmt.page_size {
+                0 => (0, self.visible_len()),
+                size => (
+                    self.page * size,
+                    (self.page * size + size).min(self.visible_len()),
+                ),
+            };
+            for i in start..end {
+                let label = self.visible_index(i).map_or("", |i| &self.labels[i]);
+                let index = format_args!("{open}{}{close}", i + 1);
+                writeln!(write, "{}{label}", fmt.index_style.paint(index))?;
+            }
+            if pages > 1 {
+                let indicator = format::fill_page_indicator(
+                    fmt.page_indicator,
+                    self.page + 1,
+                    pages,
+                    "n: next, p: previous",
+                );
+                writeln!(write, "{}", fmt.index_style.paint(indicator))?;
             }
         }
         if fmt.list_msg_pos == Position::Bottom || !self.is_first_prompt && fmt.repeat_prompt {
@@ -50,7 +159,7 @@ impl<'fmt, const N: usize, T> Promptable for Selected<'_, 'fmt, N, T> {
             } else {
                 self.title.take()
             } {
-                write!(write, "{}{}", fmt.msg_prefix, title)?;
+                self.write_msg(&mut write, title, fmt)?;
                 if fmt.break_line {
                     writeln!(write)?;
                 }
@@ -59,30 +168,472 @@ impl<'fmt, const N: usize, T> Promptable for Selected<'_, 'fmt, N, T> {
 
         self.is_first_prompt = false;
 
-        write!(write, "{}", fmt.input_prefix)?;
-        write.flush()?;
+        write!(write, "{}", fmt.input_style.paint(fmt.input_prefix))
+    }
 
-        let mut s = String::new();
-        read.read_line(&mut s)?;
-        let i = match s.trim().parse::<usize>() {
-            Ok(i) if i >= 1 && i <= self.values.len() => i,
-            _ => return Ok(ControlFlow::Continue(())),
+    fn parse(
+        &mut self, input: &str, fmt: &ExpandedSelectedFmtRules<'_>,
+    ) -> ControlFlow<T, Rejection> {
+        let len = self.visible_len();
+        let out = match (input.trim(), self.defaults.first().copied()) {
+            ("", Some(default)) => self.values[default].take(),
+            (s, _) => match s.parse::<usize>() {
+                Ok(i) if i >= 1 => self
+                    .visible_index(i - 1)
+                    .and_then(|i| self.values[i].take()),
+                Ok(_) => None,
+                Err(_) => match self.find_label(s) {
+                    Ok(i) => i.and_then(|i| self.values[i].take()),
+                    Err(reason) => return ControlFlow::Continue(Rejection::new(reason, fmt)),
+                },
+            },
+        };
+
+        match out {
+            Some(out) => ControlFlow::Break(out),
+            None => {
+                let reason = format!("choose a number between 1 and {len}");
+                ControlFlow::Continue(Rejection::new(reason, fmt))
+            }
+        }
+    }
+
+    /// Returns the index of the item matching the input, depending on the label match mode.
+    ///
+    /// Returns an error with the reason of the rejection if the input matches several items. An
+    /// empty input never matches, as every label starts with it.
+    pub(crate) fn find_label(&self, input: &str) -> Result<Option<usize>, String> {
+        if input.is_empty() || self.label_match == LabelMatch::Disabled {
+            return Ok(None);
+        }
+        if let Some(i) = self.labels.iter().position(|label| label == input) {
+            return Ok(Some(i));
+        }
+        if self.label_match == LabelMatch::Exact {
+            return Ok(None);
+        }
+
+        let lowered = input.to_lowercase();
+        let matching = |f: &dyn Fn(&str) -> bool| {
+            self.labels
+                .iter()
+                .enumerate()
+                .filter(|(_, label)| f(&label.to_lowercase()))
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>()
         };
 
-        match self.values[i - 1].take() {
-            Some(out) => Ok(ControlFlow::Break(out)),
-            None => Ok(ControlFlow::Continue(())),
+        let mut found = matching(&|label| label == lowered);
+        if found.is_empty() && self.label_match == LabelMatch::Prefix {
+            found = matching(&|label| label.starts_with(&lowered));
+        }
+
+        match found[..] {
+            [] => Ok(None),
+            [i] => Ok(Some(i)),
+            _ => {
+                let labels = found
+                    .iter()
+                    .map(|i| self.labels[*i].as_ref())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Err(format!("\"{input}\" matches several items: {labels}"))
+            }
+        }
+    }
+
+    /// Returns whether the input is the whole label of an item, depending on the label match
+    /// mode.
+    fn is_label(&self, input: &str) -> bool {
+        match self.label_match {
+            LabelMatch::Disabled => false,
+            LabelMatch::Exact => self.labels.iter().any(|label| label == input),
+            LabelMatch::CaseInsensitive | LabelMatch::Prefix => {
+                let lowered = input.to_lowercase();
+                self.labels
+                    .iter()
+                    .any(|label| label.to_lowercase() == lowered)
+            }
+        }
+    }
+
+    /// Handles the input if it's a command, i.e. a page navigation or a search term.
+    ///
+    /// The input is never a command if it's an index or the whole label of an item. The page
+    /// navigation takes precedence over the labels that merely start with the input. Returns
+    /// whether the input was a command, so the list must be displayed again, or an error with the
+    /// reason of the rejection if the command failed.
+    fn command(&mut self, input: &str, page_size: usize) -> Result<bool, String> {
+        let input = input.trim();
+        if input.is_empty() || input.parse::<usize>().is_ok() || self.is_label(input) {
+            return Ok(false);
+        }
+
+        let pages = self.pages(page_size);
+        if pages > 1 {
+            if input.eq_ignore_ascii_case("n") {
+                if self.page + 1 == pages {
+                    return Err("this is the last page".to_owned());
+                }
+                self.page += 1;
+                self.is_first_prompt = true;
+                return Ok(true);
+            }
+            if input.eq_ignore_ascii_case("p") {
+                if self.page == 0 {
+                    return Err("this is the first page".to_owned());
+                }
+                self.page -= 1;
+                self.is_first_prompt = true;
+                return Ok(true);
+            }
+        }
+
+        if matches!(self.find_label(input), Ok(Some(_))) {
+            return Ok(false);
+        }
+        if self.fuzzy {
+            self.search(input)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Filters the displayed items with the search term.
+    ///
+    /// Returns an error with the reason of the rejection if no item matches the search term.
+    fn search(&mut self, input: &str) -> Result<bool, String> {
+        let filter = (0..self.len())
+            .filter(|i| fuzzy_match(&self.labels[*i], input))
+            .collect::<Vec<_>>();
+        if filter.is_empty() {
+            return Err(format!("no item matches \"{input}\""));
+        }
+
+        self.filter = Some(filter);
+        self.page = 0;
+        self.is_first_prompt = true;
+        Ok(true)
+    }
+
+    /// Writes the title with its prefixes, the default items and the message suffix, without any
+    /// line break.
+    fn write_msg<W: io::Write>(
+        &self, mut write: W, title: &str, fmt: &ExpandedSelectedFmtRules<'_>,
+    ) -> io::Result<()> {
+        let step = format::fill_step_prefix(fmt.step_prefix, fmt.step, fmt.attempt);
+        let title = format_args!("{}{step}{title}", fmt.msg_prefix);
+        write!(write, "{}", fmt.msg_style.paint(title))?;
+        self.write_default(&mut write, fmt)?;
+        if !fmt.msg_suffix.is_empty() {
+            write!(write, "{}", fmt.msg_style.paint(fmt.msg_suffix))?;
+        }
+        Ok(())
+    }
+
+    /// Writes the title with its prefixes, the default items and the message suffix, if the title
+    /// must be displayed.
+    #[cfg(feature = "crossterm")]
+    pub(crate) fn write_title<W: io::Write>(
+        &self, mut write: W, fmt: &ExpandedSelectedFmtRules<'_>,
+    ) -> io::Result<()> {
+        if let Some(title) = self.title {
+            self.write_msg(&mut write, title, fmt)?;
+        }
+        Ok(())
+    }
+
+    fn write_default<W: io::Write>(
+        &self, mut write: W, fmt: &ExpandedSelectedFmtRules<'_>,
+    ) -> io::Result<()> {
+        if self.defaults.is_empty() {
+            return Ok(());
+        }
+
+        let (open, close) = fmt.default_surrounds;
+        write!(write, "{open}{}", fmt.default_label)?;
+        for (i, default) in self.defaults.iter().enumerate() {
+            if i > 0 {
+                write!(write, ",")?;
+            }
+            write!(write, "{}", default + 1)?;
+        }
+        write!(write, "{close}")
+    }
+
+    pub(crate) fn prompt_once<R, W>(
+        &mut self, read: R, write: W, fmt: &SelectedFmtRules<'_>,
+    ) -> io::Result<ControlFlow<T, Rejection>>
+    where
+        R: io::BufRead,
+        W: io::Write,
+    {
+        self.prompt_once_with(read, write, fmt, Self::parse)
+    }
+
+    /// Prompts the list, and parses the input with the provided function.
+    pub(crate) fn prompt_once_with<R, W, O, F>(
+        &mut self, mut read: R, mut write: W, fmt: &SelectedFmtRules<'_>, parse: F,
+    ) -> io::Result<ControlFlow<O, Rejection>>
+    where
+        R: io::BufRead,
+        W: io::Write,
+        F: FnOnce(&mut Self, &str, &ExpandedSelectedFmtRules<'_>) -> ControlFlow<O, Rejection>,
+    {
+        let fmt = fmt.expand();
+        loop {
+            self.write_prompt(&mut write, &fmt)?;
+            write.flush()?;
+
+            let input = crate::read_line(&mut read)?;
+            match self.command(&input, fmt.page_size) {
+                Ok(true) => continue,
+                Ok(false) => return Ok(parse(self, &input, &fmt)),
+                Err(reason) => return Ok(ControlFlow::Continue(Rejection::new(reason, &fmt))),
+            }
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) async fn prompt_once_async<R, W>(
+        &mut self, read: R, write: W, fmt: &SelectedFmtRules<'_>,
+    ) -> io::Result<ControlFlow<T, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        self.prompt_once_with_async(read, write, fmt, Self::parse)
+            .await
+    }
+
+    /// Prompts the list, and parses the input with the provided function, asynchronously.
+    #[cfg(feature = "async")]
+    pub(crate) async fn prompt_once_with_async<R, W, O, F>(
+        &mut self, mut read: R, mut write: W, fmt: &SelectedFmtRules<'_>, parse: F,
+    ) -> io::Result<ControlFlow<O, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+        F: FnOnce(&mut Self, &str, &ExpandedSelectedFmtRules<'_>) -> ControlFlow<O, Rejection>,
+    {
+        let fmt = fmt.expand();
+        loop {
+            let mut buf = Vec::new();
+            self.write_prompt(&mut buf, &fmt)?;
+            crate::write_all_async(&mut write, &buf).await?;
+
+            let input = crate::read_line_async(&mut read).await?;
+            match self.command(&input, fmt.page_size) {
+                Ok(true) => continue,
+                Ok(false) => return Ok(parse(self, &input, &fmt)),
+                Err(reason) => return Ok(ControlFlow::Continue(Rejection::new(reason, &fmt))),
+            }
         }
     }
 }
 
+#[cfg(feature = "crossterm")]
+#[cfg_attr(nightly, doc(cfg(feature = "crossterm")))]
+impl<const N: usize, T> crate::RawPromptable for Selected<'_, '_, N, T> {
+    fn prompt_raw_once<K, W>(
+        &mut self, keys: K, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<Self::Output>
+    where
+        K: crate::raw::KeySource,
+        W: io::Write,
+    {
+        crate::raw::select(&mut self.list, keys, write, fmt)
+    }
+}
+
+/// Returns whether the characters of the search term appear in the label in the same order,
+/// regardless of the case.
+fn fuzzy_match(label: &str, term: &str) -> bool {
+    let mut label = label.chars().flat_map(char::to_lowercase);
+    term.chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .all(|c| label.any(|l| l == c))
+}
+
+/// Promptable type for selectable inputs.
+///
+/// See the [`selected()`] function for more information.
+#[derive(Clone)]
+pub struct Selected<'a, 'fmt, const N: usize, T> {
+    list: SelectList<'a, T>,
+    _marker: PhantomData<&'fmt ()>,
+}
+
+impl<const N: usize, T> Selected<'_, '_, N, T> {
+    /// Gives a default item to the prompt, selected when the user enters an empty input.
+    ///
+    /// The `index` is the position of the item in the list provided to the [`selected()`]
+    /// function, starting from 0. The default item is displayed right after the message by its
+    /// number in the list, with the [`default_surrounds`](crate::format::FmtRule::default_surrounds)
+    /// and [`default_label`](crate::format::FmtRule::default_label) format rules.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds of the list.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ineed::prelude::*;
+    /// let license = ineed::selected("License", [("MIT", 1), ("GPL", 2), ("BSD", 3)])
+    ///   .default(0)
+    ///   .prompt()
+    ///   .unwrap();
+    /// ```
+    ///
+    /// The above example will show something similar to this:
+    /// ```txt
+    /// [1] - MIT
+    /// [2] - GPL
+    /// [3] - BSD
+    /// - License [default: 1]
+    /// >
+    /// ```
+    pub fn default(mut self, index: usize) -> Self {
+        self.list.set_defaults([index]);
+        self
+    }
+
+    /// Sets the way the user input is matched against the labels of the items.
+    ///
+    /// By default, the user can select an item by its index, or by its exact label.
+    /// See [`LabelMatch`] for the other modes.
+    ///
+    /// If the input matches several items, it is rejected and the matching labels are displayed
+    /// to the user.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ineed::prelude::*;
+    /// let license = ineed::selected("License", [("MIT", 1), ("GPL", 2), ("GNU AGPL", 3)])
+    ///   .label_match(ineed::LabelMatch::Prefix)
+    ///   .prompt()
+    ///   .unwrap();
+    /// ```
+    ///
+    /// In this example, entering `mit` or `gp` selects the first or the second item, but `g` is
+    /// rejected as it matches both `GPL` and `GNU AGPL`.
+    pub fn label_match(mut self, label_match: LabelMatch) -> Self {
+        self.list.label_match = label_match;
+        self
+    }
+
+    /// Enables the fuzzy filtering of the items, to search them in long lists.
+    ///
+    /// When enabled, an input that isn't an index or a label is used as a search term: only the
+    /// items whose label contains the characters of the term, in the same order, are displayed
+    /// again. The user can then enter an index in the filtered list, or search again among all
+    /// the items. If no item matches the term, the input is rejected.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ineed::prelude::*;
+    /// let license = ineed::selected(
+    ///   "License",
+    ///   [("MIT", 1), ("Apache-2.0", 2), ("GPL-3.0", 3), ("LGPL-3.0", 4), ("BSD-3-Clause", 5)],
+    /// )
+    ///   .fuzzy(true)
+    ///   .prompt()
+    ///   .unwrap();
+    /// ```
+    ///
+    /// Entering `gpl` in this example will show something similar to this:
+    /// ```txt
+    /// [1] - MIT
+    /// [2] - Apache-2.0
+    /// [3] - GPL-3.0
+    /// [4] - LGPL-3.0
+    /// [5] - BSD-3-Clause
+    /// - License
+    /// > gpl
+    /// [1] - GPL-3.0
+    /// [2] - LGPL-3.0
+    /// >
+    /// ```
+    ///
+    /// Then entering `2` selects `LGPL-3.0`.
+    ///
+    /// The filtered list is paginated like the whole list, with the
+    /// [`page_size`](crate::format::FmtRule::page_size) format rule.
+    pub fn fuzzy(mut self, fuzzy: bool) -> Self {
+        self.list.fuzzy = fuzzy;
+        self
+    }
+}
+
+impl<'fmt, const N: usize, T> Promptable for Selected<'_, 'fmt, N, T> {
+    type Output = T;
+    type FmtRules = SelectedFmtRules<'fmt>;
+
+    fn prompt_once<R, W>(
+        &mut self, read: R, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: io::BufRead,
+        W: io::Write,
+    {
+        self.list.prompt_once(read, write, fmt)
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(nightly, doc(cfg(feature = "async")))]
+impl<const N: usize, T> crate::AsyncPromptable for Selected<'_, '_, N, T> {
+    async fn prompt_once_async<R, W>(
+        &mut self, read: R, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        self.list.prompt_once_async(read, write, fmt).await
+    }
+}
+
 /// Returns a type that prompts the user a selectable value.
 ///
 /// The values are presented as an indexed list. The user must enter the index of an item in the
-/// list to select it. The output is the value bound to this index.
+/// list, or its label, to select it. The output is the value bound to this item. The way the
+/// labels are matched can be customized with the [`Selected::label_match`] method.
 ///
 /// The promptable checks for each try that the input is an index, and checks that the index
-/// is in bound.
+/// is in bound. Otherwise, the user is asked to choose a number in the bounds of the list.
+/// For long lists, the user can also search the items, see the [`Selected::fuzzy`] method.
+///
+/// # Pagination
+///
+/// Long lists can be split into pages with the
+/// [`page_size`](crate::format::FmtRule::page_size) format rule. Only the items of the current
+/// page are displayed, with a page indicator (see the
+/// [`page_indicator`](crate::format::FmtRule::page_indicator) format rule), and the user can enter
+/// `n` or `p` to display the next or the previous page. The items keep their index in the whole list, so the user can
+/// select an item that isn't on the current page.
+///
+/// ```no_run
+/// # use ineed::prelude::*;
+/// let countries = ["France", "Germany", "Italy", "Spain", "Sweden"];
+/// let country = ineed::selected("Country", countries.map(|c| (c, c)))
+///   .fmt(ineed::fmt().page_size(2))
+///   .prompt()
+///   .unwrap();
+/// ```
+///
+/// This will show something similar to this:
+/// ```txt
+/// [1] - France
+/// [2] - Germany
+/// page 1/3 (n: next, p: previous)
+/// - Country
+/// >
+/// ```
 ///
 /// # Example
 ///
@@ -146,21 +697,8 @@ impl<'fmt, const N: usize, T> Promptable for Selected<'_, 'fmt, N, T> {
 pub fn selected<'a, 'fmt, const N: usize, T>(
     title: &'a str, list: [(&'a str, T); N],
 ) -> Selected<'a, 'fmt, N, T> {
-    fn split<const N: usize, A, B>(arr: [(A, B); N]) -> ([A; N], [B; N]) {
-        use std::array::from_fn;
-        let mut arr = arr.map(|(a, b)| (Some(a), Some(b)));
-        let a = from_fn(|i| arr[i].0.take().unwrap());
-        let b = from_fn(|i| arr[i].1.take().unwrap());
-        (a, b)
-    }
-
-    let (msgs, values) = split(list.map(|(a, b)| (a, Some(b))));
-
     Selected {
-        title: Some(title),
-        msgs: Some(msgs),
-        values,
-        is_first_prompt: true,
+        list: SelectList::new(title, list),
         _marker: PhantomData,
     }
 }
@@ -227,7 +765,7 @@ mod tests {
             {open}2{close}bar\n\
             {open}3{close}foobar\n\
             {opt_title_bottom}{opt_nl}\
-            {input_prefix}{input_prefix}{input_prefix}{input_prefix}{input_prefix}",
+            {input_prefix}{err}{input_prefix}{err}{input_prefix}{err}{input_prefix}{err}{input_prefix}",
             opt_title_top = if let Position::Top = default_fmt.list_msg_pos {
                 format!("{title}\n")
             } else {
@@ -241,7 +779,11 @@ mod tests {
                 ""
             },
             opt_nl = if default_fmt.break_line { "\n" } else { "" },
-            input_prefix = default_fmt.input_prefix
+            input_prefix = default_fmt.input_prefix,
+            err = format!(
+                "{}choose a number between 1 and 3\n",
+                default_fmt.err_prefix
+            ),
         );
         assert_eq!(String::from_utf8(output)?, expected_msg);
 
@@ -269,14 +811,18 @@ mod tests {
             {open}1{close}foo\n\
             {open}2{close}bar\n\
             {open}3{close}foobar\n\
-            {input_prefix}\
-            {msg_prefix}booga{input_prefix}\
-            {msg_prefix}booga{input_prefix}\
+            {input_prefix}{err}\
+            {msg_prefix}booga{input_prefix}{err}\
+            {msg_prefix}booga{input_prefix}{err}\
             {msg_prefix}booga{input_prefix}",
             msg_prefix = default_fmt.msg_prefix,
             open = default_fmt.list_surrounds.0,
             close = default_fmt.list_surrounds.1,
-            input_prefix = default_fmt.input_prefix
+            input_prefix = default_fmt.input_prefix,
+            err = format!(
+                "{}choose a number between 1 and 3\n",
+                default_fmt.err_prefix
+            ),
         );
         assert_eq!(String::from_utf8(output)?, expected_msg);
 
@@ -304,14 +850,18 @@ mod tests {
             {open}1{close}foo\n\
             {open}2{close}bar\n\
             {open}3{close}foobar\n\
-            {input_prefix}\
-            {msg_prefix}booga\n{input_prefix}\
-            {msg_prefix}booga\n{input_prefix}\
+            {input_prefix}{err}\
+            {msg_prefix}booga\n{input_prefix}{err}\
+            {msg_prefix}booga\n{input_prefix}{err}\
             {msg_prefix}booga\n{input_prefix}",
             msg_prefix = default_fmt.msg_prefix,
             open = default_fmt.list_surrounds.0,
             close = default_fmt.list_surrounds.1,
-            input_prefix = default_fmt.input_prefix
+            input_prefix = default_fmt.input_prefix,
+            err = format!(
+                "{}choose a number between 1 and 3\n",
+                default_fmt.err_prefix
+            ),
         );
         assert_eq!(String::from_utf8(output)?, expected_msg);
 
@@ -370,7 +920,424 @@ mod tests {
             "<1> foo\n\
             <2> bar\n\
             <3> foobar\n\
-            -> booga: -> booga: -> booga: -> booga: -> booga: "
+            -> booga: ! choose a number between 1 and 3\n\
+            -> booga: ! choose a number between 1 and 3\n\
+            -> booga: ! choose a number between 1 and 3\n\
+            -> booga: ! choose a number between 1 and 3\n\
+            -> booga: "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn select_by_label() -> anyhow::Result<()> {
+        let input = "mit\nGPL\n".as_bytes();
+        let mut output = Vec::new();
+        let res =
+            crate::selected("License", [("MIT", 1), ("GPL", 2)]).prompt_with(input, &mut output)?;
+        assert_eq!(res, 2);
+        assert_eq!(
+            String::from_utf8(output)?,
+            "[1] - MIT\n[2] - GPL\n- License\n> ! choose a number between 1 and 2\n> "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn label_match_modes() -> anyhow::Result<()> {
+        use crate::LabelMatch;
+        use std::ops::ControlFlow;
+
+        let list = || [("MIT", 1), ("GPL", 2), ("GNU AGPL", 3), ("gpl", 4)];
+        let select = |mode, input: &str| {
+            crate::selected("", list())
+                .label_match(mode)
+                .fmt(crate::fmt().input_prefix(""))
+                .prompt_once(input.as_bytes(), std::io::sink(), &Default::default())
+        };
+
+        assert!(matches!(
+            select(LabelMatch::Disabled, "MIT\n")?,
+            ControlFlow::Continue(_)
+        ));
+        assert!(matches!(
+            select(LabelMatch::Disabled, "2\n")?,
+            ControlFlow::Break(2)
+        ));
+        assert!(matches!(
+            select(LabelMatch::Exact, "gpl\n")?,
+            ControlFlow::Break(4)
+        ));
+        assert!(matches!(
+            select(LabelMatch::Exact, "mit\n")?,
+            ControlFlow::Continue(_)
+        ));
+        assert!(matches!(
+            select(LabelMatch::CaseInsensitive, "mit\n")?,
+            ControlFlow::Break(1)
+        ));
+        assert!(matches!(
+            select(LabelMatch::CaseInsensitive, "gnu\n")?,
+            ControlFlow::Continue(_)
+        ));
+        assert!(matches!(
+            select(LabelMatch::Prefix, "gnu\n")?,
+            ControlFlow::Break(3)
+        ));
+        assert!(matches!(
+            select(LabelMatch::Prefix, "Mi\n")?,
+            ControlFlow::Break(1)
+        ));
+
+        let ControlFlow::Continue(rejection) = select(LabelMatch::CaseInsensitive, "Gpl\n")? else {
+            panic!("the input should be ambiguous");
+        };
+        assert_eq!(
+            rejection.reason(),
+            "\"Gpl\" matches several items: GPL, gpl"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty_input_with_prefix() -> anyhow::Result<()> {
+        use std::ops::ControlFlow;
+
+        let flow = crate::selected("", [("MIT", 1)])
+            .label_match(crate::LabelMatch::Prefix)
+            .prompt_once("\n".as_bytes(), std::io::sink(), &Default::default())?;
+        let ControlFlow::Continue(rejection) = flow else {
+            panic!("the empty input should be rejected");
+        };
+        assert_eq!(rejection.reason(), "choose a number between 1 and 1");
+
+        let flow = crate::selected("", [("MIT", 1), ("GPL", 2)])
+            .label_match(crate::LabelMatch::Prefix)
+            .prompt_once("\n".as_bytes(), std::io::sink(), &Default::default())?;
+        let ControlFlow::Continue(rejection) = flow else {
+            panic!("the empty input should be rejected");
+        };
+        assert_eq!(rejection.reason(), "choose a number between 1 and 2");
+
+        Ok(())
+    }
+
+    #[test]
+    fn ambiguous_prefix() -> anyhow::Result<()> {
+        let input = "G\ngp\n".as_bytes();
+        let mut output = Vec::new();
+        let res = crate::selected("License", [("MIT", 1), ("GPL", 2), ("GNU AGPL", 3)])
+            .label_match(crate::LabelMatch::Prefix)
+            .prompt_with(input, &mut output)?;
+        assert_eq!(res, 2);
+        assert_eq!(
+            String::from_utf8(output)?,
+            "[1] - MIT\n[2] - GPL\n[3] - GNU AGPL\n- License\n> \
+             ! \"G\" matches several items: GPL, GNU AGPL\n> "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fuzzy_filter() -> anyhow::Result<()> {
+        let list = [
+            ("MIT", 1),
+            ("Apache-2.0", 2),
+            ("GPL-3.0", 3),
+            ("LGPL-3.0", 4),
+            ("BSD-3-Clause", 5),
+        ];
+        let input = "gpl\n3\n2\n".as_bytes();
+        let mut output = Vec::new();
+        let res = crate::selected("License", list)
+            .fuzzy(true)
+            .prompt_with(input, &mut output)?;
+        assert_eq!(res, 4);
+        assert_eq!(
+            String::from_utf8(output)?,
+            "[1] - MIT\n[2] - Apache-2.0\n[3] - GPL-3.0\n[4] - LGPL-3.0\n[5] - BSD-3-Clause\n\
+             - License\n> \
+             [1] - GPL-3.0\n[2] - LGPL-3.0\n> \
+             ! choose a number between 1 and 2\n> "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fuzzy_search_again() -> anyhow::Result<()> {
+        let list = [("foo", 1), ("bar", 2), ("baz", 3), ("qux", 4)];
+        let input = "ba\nqx\nxyz\nbz\n1\n".as_bytes();
+        let mut output = Vec::new();
+        let res = crate::selected("", list)
+            .fuzzy(true)
+            .fmt(crate::fmt().msg_prefix("").input_prefix(""))
+            .prompt_with(input, &mut output)?;
+        assert_eq!(res, 3);
+        assert_eq!(
+            String::from_utf8(output)?,
+            "[1] - foo\n[2] - bar\n[3] - baz\n[4] - qux\n\n\
+             [1] - bar\n[2] - baz\n\
+             [1] - qux\n\
+             ! no item matches \"xyz\"\n\
+             [1] - baz\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fuzzy_labels_first() -> anyhow::Result<()> {
+        let res = crate::selected("", [("foo", 1), ("foobar", 2)])
+            .fuzzy(true)
+            .prompt_with("foo\n".as_bytes(), std::io::empty())?;
+        assert_eq!(res, 1);
+
+        let res = crate::selected("", [("foo", 1), ("foobar", 2)])
+            .fuzzy(false)
+            .prompt_with("fb\n1\n".as_bytes(), std::io::empty())?;
+        assert_eq!(res, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn pagination() -> anyhow::Result<()> {
+        let list = [("a", 1), ("b", 2), ("c", 3), ("d", 4), ("e", 5)];
+        let input = "p\nn\nN\nn\n1\n".as_bytes();
+        let mut output = Vec::new();
+        let res = crate::selected("Letter", list)
+            .fmt(crate::fmt().page_size(2))
+            .prompt_with(input, &mut output)?;
+        assert_eq!(res, 1);
+        assert_eq!(
+            String::from_utf8(output)?,
+            "[1] - a\n[2] - b\npage 1/3 (n: next, p: previous)\n- Letter\n> \
+             ! this is the first page\n> \
+             [3] - c\n[4] - d\npage 2/3 (n: next, p: previous)\n> \
+             [5] - e\npage 3/3 (n: next, p: previous)\n> \
+             ! this is the last page\n> "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn custom_page_indicator() -> anyhow::Result<()> {
+        let list = [("a", 1), ("b", 2), ("c", 3)];
+        let mut output = Vec::new();
+        let res = crate::selected("", list)
+            .fmt(
+                crate::fmt()
+                    .page_size(2)
+                    .page_indicator("-- {page}/{pages} [{keys}] --")
+                    .msg_prefix("")
+                    .input_prefix(""),
+            )
+            .prompt_with("3\n".as_bytes(), &mut output)?;
+        assert_eq!(res, 3);
+        assert_eq!(
+            String::from_utf8(output)?,
+            "[1] - a\n[2] - b\n-- 1/2 [n: next, p: previous] --\n\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn pagination_before_prefix() -> anyhow::Result<()> {
+        let list = [("alpha", 1), ("beta", 2), ("nova", 3), ("x", 4)];
+        let mut output = Vec::new();
+        let res = crate::selected("", list)
+            .label_match(crate::LabelMatch::Prefix)
+            .fmt(crate::fmt().page_size(2).msg_prefix("").input_prefix(""))
+            .prompt_with("n\nno\n".as_bytes(), &mut output)?;
+        assert_eq!(res, 3);
+        assert_eq!(
+            String::from_utf8(output)?,
+            "[1] - alpha\n[2] - beta\npage 1/2 (n: next, p: previous)\n\n\
+             [3] - nova\n[4] - x\npage 2/2 (n: next, p: previous)\n"
+        );
+
+        let res = crate::selected("", [("alpha", 1), ("n", 2), ("x", 3)])
+            .label_match(crate::LabelMatch::Prefix)
+            .fmt(crate::fmt().page_size(2))
+            .prompt_with("N\n".as_bytes(), std::io::sink())?;
+        assert_eq!(res, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn pagination_single_page() -> anyhow::Result<()> {
+        let mut output = Vec::new();
+        let res = crate::selected("", [("n", 1), ("p", 2)])
+            .fmt(crate::fmt().page_size(2).msg_prefix("").input_prefix(""))
+            .prompt_with("p\n".as_bytes(), &mut output)?;
+        assert_eq!(res, 2);
+        assert_eq!(String::from_utf8(output)?, "[1] - n\n[2] - p\n\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn paginated_fuzzy_filter() -> anyhow::Result<()> {
+        let list = (1..=30).map(|i| (format!("item {i}"), i));
+        let input = "n\nm3\nn\n2\n".as_bytes();
+        let mut output = Vec::new();
+        let res = crate::selected_list("", list)
+            .fuzzy(true)
+            .fmt(crate::fmt().page_size(10).msg_prefix("").input_prefix(""))
+            .prompt_with(input, &mut output)?;
+        assert_eq!(res, 13);
+        let output = String::from_utf8(output)?;
+        assert!(output.contains("[11] - item 11\n"));
+        assert!(output.ends_with(
+            "[1] - item 3\n[2] - item 13\n[3] - item 23\n[4] - item 30\n\
+             ! no item matches \"n\"\n"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fuzzy_match() {
+        assert!(super::fuzzy_match("BSD-3-Clause", "bsd3"));
+        assert!(super::fuzzy_match("Apache-2.0", "ap 2"));
+        assert!(!super::fuzzy_match("GPL-3.0", "lgpl"));
+        assert!(super::fuzzy_match("anything", ""));
+    }
+
+    #[test]
+    fn msg_suffix() -> anyhow::Result<()> {
+        let mut output = Vec::new();
+        crate::selected("booga", [("foo", 1), ("bar", 2)])
+            .default(0)
+            .fmt(crate::fmt().msg_suffix(" ?").list_msg_pos(Position::Top))
+            .prompt_with(b"1\n".as_slice(), &mut output)?;
+        assert_eq!(
+            String::from_utf8(output)?,
+            "- booga [default: 1] ?\n[1] - foo\n[2] - bar\n> "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn inline_layout() -> anyhow::Result<()> {
+        let mut output = Vec::new();
+        let res = crate::selected("License", [("MIT", 1), ("GPL", 2)])
+            .fmt(crate::fmt().inline().list_surrounds("", ". "))
+            .prompt_with(b"3\n2\n".as_slice(), &mut output)?;
+
+        assert_eq!(res, 2);
+        assert_eq!(
+            String::from_utf8(output)?,
+            "1. MIT\n2. GPL\nLicense: ! choose a number between 1 and 2\nLicense: "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn styles() -> anyhow::Result<()> {
+        use crate::format::{Color, Style};
+
+        let mut output = Vec::new();
+        crate::selected("booga", [("foo", 1), ("bar", 2)])
+            .fmt(
+                crate::fmt()
+                    .styled(true)
+                    .index_style(Style::new().dim())
+                    .msg_style(Style::new().underline().fg(Color::Green)),
+            )
+            .prompt_with(b"1\n".as_slice(), &mut output)?;
+        assert_eq!(
+            String::from_utf8(output)?,
+            "\x1b[2m[1] - \x1b[0mfoo\n\x1b[2m[2] - \x1b[0mbar\n\
+             \x1b[4;32m- booga\x1b[0m\n> "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn eof_input() {
+        let input = b"boo\n0\n".as_slice();
+        let res = crate::selected("booga", [("foo", 1000), ("bar", 2000)])
+            .prompt_with(input, std::io::empty());
+        assert_eq!(res.unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn default_item() -> anyhow::Result<()> {
+        let input = b"\n".as_slice();
+        let mut output = Vec::new();
+
+        let res = crate::selected("booga", [("foo", 1000), ("bar", 2000), ("foobar", 3000)])
+            .default(1)
+            .fmt(
+                crate::fmt()
+                    .msg_prefix("-> ")
+                    .input_prefix(": ")
+                    .break_line(false)
+                    .list_surrounds("<", "> ")
+                    .list_msg_pos(Position::Bottom),
+            )
+            .prompt_with(input, &mut output)?;
+        assert_eq!(res, 2000);
+
+        assert_eq!(
+            String::from_utf8(output)?.as_str(),
+            "<1> foo\n\
+            <2> bar\n\
+            <3> foobar\n\
+            -> booga [default: 2]: "
+        );
+
+        let res = crate::selected("booga", [("foo", 1000), ("bar", 2000), ("foobar", 3000)])
+            .default(1)
+            .prompt_with(b"3\n".as_slice(), std::io::empty())?;
+        assert_eq!(res, 3000);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_input() -> anyhow::Result<()> {
+        use tokio::io::{AsyncReadExt as _, AsyncWriteExt as _, BufReader};
+
+        let (mut input, read) = tokio::io::duplex(1024);
+        let (write, mut output) = tokio::io::duplex(1024);
+        input.write_all(b"bim\n1\n").await?;
+        drop(input);
+
+        let res = crate::selected("booga", [("foo", 1000), ("bar", 2000)])
+            .fmt(
+                crate::fmt()
+                    .msg_prefix("-> ")
+                    .input_prefix(": ")
+                    .repeat_prompt(true)
+                    .break_line(false)
+                    .list_surrounds("<", "> "),
+            )
+            .prompt_with_async(BufReader::new(read), write)
+            .await?;
+        assert_eq!(res, 1000);
+
+        let mut s = String::new();
+        output.read_to_string(&mut s).await?;
+        assert_eq!(
+            s,
+            "<1> foo\n\
+            <2> bar\n\
+            -> booga: ! choose a number between 1 and 2\n\
+            -> booga: "
         );
 
         Ok(())
@@ -0,0 +1,231 @@
+use std::{borrow::Cow, io, marker::PhantomData, ops::ControlFlow};
+
+use crate::{LabelMatch, Promptable, Rejection, format::rules::SelectedFmtRules};
+
+use super::selected::SelectList;
+
+/// Promptable type for selectable inputs, with a list built at runtime.
+///
+/// See the [`selected_list()`] function for more information.
+#[derive(Clone)]
+pub struct SelectedList<'a, 'fmt, T> {
+    list: SelectList<'a, T>,
+    _marker: PhantomData<&'fmt ()>,
+}
+
+impl<T> SelectedList<'_, '_, T> {
+    /// Gives a default item to the prompt, selected when the user enters an empty input.
+    ///
+    /// See the [`Selected::default`](crate::Selected::default) method for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds of the list.
+    pub fn default(mut self, index: usize) -> Self {
+        self.list.set_defaults([index]);
+        self
+    }
+
+    /// Sets the way the user input is matched against the labels of the items.
+    ///
+    /// See the [`Selected::label_match`](crate::Selected::label_match) method for more
+    /// information.
+    pub fn label_match(mut self, label_match: LabelMatch) -> Self {
+        self.list.label_match = label_match;
+        self
+    }
+
+    /// Enables the fuzzy filtering of the items, to search them in long lists.
+    ///
+    /// See the [`Selected::fuzzy`](crate::Selected::fuzzy) method for more information.
+    pub fn fuzzy(mut self, fuzzy: bool) -> Self {
+        self.list.fuzzy = fuzzy;
+        self
+    }
+
+    /// Returns the amount of items in the list.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns whether the list is empty.
+    ///
+    /// Prompting an empty list returns an error, as there is nothing to select.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn check_empty(&self) -> io::Result<()> {
+        if self.is_empty() {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the list of the selectable items is empty",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<'fmt, T> Promptable for SelectedList<'_, 'fmt, T> {
+    type Output = T;
+    type FmtRules = SelectedFmtRules<'fmt>;
+
+    fn prompt_once<R, W>(
+        &mut self, read: R, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: io::BufRead,
+        W: io::Write,
+    {
+        self.check_empty()?;
+        self.list.prompt_once(read, write, fmt)
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(nightly, doc(cfg(feature = "async")))]
+impl<T> crate::AsyncPromptable for SelectedList<'_, '_, T> {
+    async fn prompt_once_async<R, W>(
+        &mut self, read: R, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        self.check_empty()?;
+        self.list.prompt_once_async(read, write, fmt).await
+    }
+}
+
+#[cfg(feature = "crossterm")]
+#[cfg_attr(nightly, doc(cfg(feature = "crossterm")))]
+impl<T> crate::RawPromptable for SelectedList<'_, '_, T> {
+    fn prompt_raw_once<K, W>(
+        &mut self, keys: K, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<Self::Output>
+    where
+        K: crate::raw::KeySource,
+        W: io::Write,
+    {
+        crate::raw::select(&mut self.list, keys, write, fmt)
+    }
+}
+
+/// Returns a type that prompts the user a selectable value, from a list built at runtime.
+///
+/// This works like the [`selected()`](crate::selected) function, with the same rendering and
+/// format rules, but the list can be any iterator of labels and values, e.g. a `Vec`. The labels
+/// can be borrowed (`&str`) or owned (`String`).
+///
+/// If the list is empty, there is nothing to select, so prompting it returns an error of kind
+/// [`InvalidInput`](io::ErrorKind::InvalidInput) without printing anything. You can check it
+/// beforehand with the [`SelectedList::is_empty`] method.
+///
+/// # Example
+///
+/// ```no_run
+/// # use ineed::prelude::*;
+/// let branches = vec!["main".to_owned(), "dev".to_owned(), "fix-123".to_owned()];
+///
+/// let branch = ineed::selected_list(
+///   "Branch to checkout",
+///   branches.iter().map(|branch| (branch.as_str(), branch)),
+/// )
+///   .prompt()
+///   .unwrap();
+/// ```
+pub fn selected_list<'a, 'fmt, I, L, T>(title: &'a str, list: I) -> SelectedList<'a, 'fmt, T>
+where
+    I: IntoIterator<Item = (L, T)>,
+    L: Into<Cow<'a, str>>,
+{
+    SelectedList {
+        list: SelectList::new(title, list),
+        _marker: PhantomData,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn owned_labels() -> anyhow::Result<()> {
+        let files = (1..=3).map(|i| (format!("file{i}.txt"), i));
+        let mut output = Vec::new();
+        let res =
+            crate::selected_list("File", files).prompt_with("4\n2\n".as_bytes(), &mut output)?;
+        assert_eq!(res, 2);
+        assert_eq!(
+            String::from_utf8(output)?,
+            "[1] - file1.txt\n[2] - file2.txt\n[3] - file3.txt\n- File\n> \
+             ! choose a number between 1 and 3\n> "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn same_output_as_selected() -> anyhow::Result<()> {
+        let input = "0\n\n".as_bytes();
+        let fmt = || crate::fmt().list_surrounds("(", ") ").repeat_prompt(true);
+
+        let mut expected = Vec::new();
+        crate::selected("Level", [("Low", 1), ("High", 2)])
+            .default(1)
+            .fmt(fmt())
+            .prompt_with(input, &mut expected)?;
+
+        let mut output = Vec::new();
+        let res = crate::selected_list("Level", vec![("Low", 1), ("High", 2)])
+            .default(1)
+            .fmt(fmt())
+            .prompt_with(input, &mut output)?;
+        assert_eq!(res, 2);
+        assert_eq!(output, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn select_by_label() -> anyhow::Result<()> {
+        let res = crate::selected_list("", vec![("foo".to_owned(), 1), ("bar".to_owned(), 2)])
+            .prompt_with("bar\n".as_bytes(), std::io::empty())?;
+        assert_eq!(res, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty_list() {
+        let mut list = crate::selected_list::<_, &str, i32>("", Vec::new());
+        assert!(list.is_empty());
+
+        let mut output = Vec::new();
+        let res = list.prompt_with("1\n".as_bytes(), &mut output);
+        assert!(matches!(res, Err(e) if e.kind() == std::io::ErrorKind::InvalidInput));
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    #[should_panic = "the default index is 2 but the list contains 2 items"]
+    fn default_out_of_bounds() {
+        let _ = crate::selected_list("", vec![("a", 1), ("b", 2)]).default(2);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_input() -> anyhow::Result<()> {
+        let (mut input, read) = tokio::io::duplex(1024);
+        tokio::io::AsyncWriteExt::write_all(&mut input, b"3\n1\n").await?;
+        drop(input);
+
+        let res = crate::selected_list("", vec![("a", 'a'), ("b", 'b')])
+            .prompt_with_async(tokio::io::BufReader::new(read), tokio::io::sink())
+            .await?;
+        assert_eq!(res, 'a');
+
+        Ok(())
+    }
+}
@@ -1,41 +1,123 @@
-use std::{io, marker::PhantomData, ops::ControlFlow, str::FromStr};
+use std::{fmt::Display, io, marker::PhantomData, ops::ControlFlow, str::FromStr};
 
-use crate::{Promptable, WrittenFmtRules, WrittenInner};
+use crate::{DefaultValue, EMPTY_INPUT, Promptable, Rejection, WrittenFmtRules, WrittenInner};
 
 /// Promptable type for separated inputs of the same type.
 ///
 /// See the [`separated()`] function for more information.
+#[derive(Clone)]
 pub struct Separated<'a, 'fmt, I, T> {
     inner: WrittenInner<'a, 'fmt>,
     sep: &'a str,
-    _marker: PhantomData<(I, T)>,
+    default: Option<DefaultValue<Vec<T>>>,
+    _marker: PhantomData<I>,
+}
+
+impl<I, T> Separated<'_, '_, I, T> {
+    /// Gives default values to the prompt, collected into the output when the user enters
+    /// an empty input.
+    ///
+    /// The default values are displayed right after the message, separated by the separator,
+    /// with the [`default_surrounds`](crate::format::FmtRule::default_surrounds) and
+    /// [`default_label`](crate::format::FmtRule::default_label) format rules.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ineed::prelude::*;
+    /// let ports: Vec<u16> = ineed::separated("Ports", ", ")
+    ///   .default([80, 443])
+    ///   .prompt()
+    ///   .unwrap();
+    /// ```
+    ///
+    /// The above example will show something similar to this:
+    /// ```txt
+    /// - Ports [default: 80, 443]
+    /// >
+    /// ```
+    pub fn default<D>(mut self, values: D) -> Self
+    where
+        D: IntoIterator<Item = T>,
+        T: Display + Clone,
+    {
+        let values = values.into_iter().collect::<Vec<_>>();
+        let shown = values
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(self.sep);
+        self.inner.set_default(shown);
+        self.default = Some((values, Vec::clone));
+        self
+    }
+}
+
+impl<I, T> Separated<'_, '_, I, T>
+where
+    I: FromIterator<T>,
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+    fn parse(&self, input: &str, fmt: &WrittenFmtRules<'_>) -> ControlFlow<I, Rejection> {
+        if input.is_empty() {
+            return match &self.default {
+                Some((values, clone)) => ControlFlow::Break(clone(values).into_iter().collect()),
+                None => ControlFlow::Continue(Rejection::new(EMPTY_INPUT, fmt)),
+            };
+        }
+        match input
+            .split(self.sep)
+            .map(|s| {
+                let s = s.trim();
+                s.parse().map_err(|e| format!("\"{s}\": {e}"))
+            })
+            .collect::<Result<I, _>>()
+        {
+            Ok(o) => ControlFlow::Break(o),
+            Err(e) => ControlFlow::Continue(Rejection::new(e, fmt)),
+        }
+    }
 }
 
 impl<'fmt, I, T> Promptable for Separated<'_, 'fmt, I, T>
 where
     I: FromIterator<T>,
     T: FromStr,
+    <T as FromStr>::Err: Display,
 {
     type Output = I;
     type FmtRules = WrittenFmtRules<'fmt>;
 
     fn prompt_once<R, W>(
         &mut self, read: R, write: W, fmt: &Self::FmtRules,
-    ) -> io::Result<ControlFlow<Self::Output>>
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
     where
         R: io::BufRead,
         W: io::Write,
     {
-        self.inner.prompt(read, write, fmt).map(|out| {
-            match out
-                .split(self.sep)
-                .map(|s| s.trim().parse())
-                .collect::<Result<I, _>>()
-            {
-                Ok(o) if !out.is_empty() => ControlFlow::Break(o),
-                _ => ControlFlow::Continue(()),
-            }
-        })
+        let input = self.inner.prompt(read, write, fmt)?;
+        Ok(self.parse(&input, fmt))
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(nightly, doc(cfg(feature = "async")))]
+impl<I, T> crate::AsyncPromptable for Separated<'_, '_, I, T>
+where
+    I: FromIterator<T>,
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+    async fn prompt_once_async<R, W>(
+        &mut self, mut read: R, mut write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        let input = self.inner.prompt_async(&mut read, &mut write, fmt).await?;
+        Ok(self.parse(&input, fmt))
     }
 }
 
@@ -61,6 +143,7 @@ pub fn separated<'a, 'fmt, I, T>(msg: &'a str, sep: &'a str) -> Separated<'a, 'f
     Separated {
         inner: WrittenInner::new(msg),
         sep,
+        default: None,
         _marker: PhantomData,
     }
 }
@@ -95,4 +178,66 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn eof_input() {
+        let input = "foo;2;3\n".as_bytes();
+        let res: std::io::Result<Vec<i32>> =
+            crate::separated("", ";").prompt_with(input, std::io::empty());
+        assert_eq!(res.unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn non_clone_values() -> anyhow::Result<()> {
+        /// A type that can be parsed, but not cloned.
+        #[derive(Debug, PartialEq)]
+        struct Name(String);
+
+        impl std::str::FromStr for Name {
+            type Err = std::convert::Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self(s.to_owned()))
+            }
+        }
+
+        let input = "foo, bar\n".as_bytes();
+        let names: Vec<Name> = crate::separated("", ",").prompt_with(input, std::io::empty())?;
+        assert_eq!(names, [Name("foo".to_owned()), Name("bar".to_owned())]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn default_values() -> anyhow::Result<()> {
+        let mut output = Vec::new();
+        let values: Vec<i32> = crate::separated("foo", ", ")
+            .default([1, 2])
+            .fmt(
+                crate::fmt()
+                    .msg_prefix("")
+                    .break_line(false)
+                    .input_prefix(": "),
+            )
+            .prompt_with("\n".as_bytes(), &mut output)?;
+        assert_eq!(values, [1, 2]);
+        assert_eq!(String::from_utf8(output)?, "foo [default: 1, 2]: ");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_any_invalid_input() -> anyhow::Result<()> {
+        let (mut input, read) = tokio::io::duplex(1024);
+        tokio::io::AsyncWriteExt::write_all(&mut input, b"foo;2;3\n1;2;3\n").await?;
+        drop(input);
+
+        let values: Vec<i32> = crate::separated("", ";")
+            .prompt_with_async(tokio::io::BufReader::new(read), tokio::io::sink())
+            .await?;
+        assert_eq!(values, [1, 2, 3]);
+
+        Ok(())
+    }
 }
@@ -1,6 +1,9 @@
 use std::{io, marker::PhantomData, ops::ControlFlow};
 
-use crate::{Promptable, format::rules::ThenFmtRules};
+use crate::{
+    Promptable, Rejection,
+    format::{Progress as _, rules::ThenFmtRules},
+};
 
 /// Used to convert a raw output into a proper output.
 ///
@@ -62,6 +65,16 @@ pub struct Then<A, B, O> {
     pub(crate) _marker: PhantomData<O>,
 }
 
+impl<A: Clone, B: Clone, O> Clone for Then<A, B, O> {
+    fn clone(&self) -> Self {
+        Self {
+            first: self.first.clone(),
+            then: self.then.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
 /// Represents promptable types that have an output type that is flattenable.
 ///
 /// This is mostly used by the [`Then`] promptable type, as its raw output is nested couples
@@ -90,7 +103,7 @@ where
 
     fn prompt_once<R, W>(
         &mut self, read: R, write: W, fmt: &Self::FmtRules,
-    ) -> io::Result<ControlFlow<O>>
+    ) -> io::Result<ControlFlow<O, Rejection>>
     where
         R: io::BufRead,
         W: io::Write,
@@ -102,7 +115,7 @@ where
 fn prompt_twice<R, W, A, B, O>(
     mut read: R, mut write: W, prompt: &mut Then<A, B, O>,
     fmt: &<Then<A, B, O> as Promptable>::FmtRules,
-) -> io::Result<ControlFlow<O>>
+) -> io::Result<ControlFlow<O, Rejection>>
 where
     R: io::BufRead,
     W: io::Write,
@@ -110,26 +123,53 @@ where
     B: Promptable,
     O: FromOutput<<Then<A, B, O> as Flattenable>::RawOutput>,
 {
-    let ControlFlow::Break(a) = prompt
+    let fmt = fmt.root();
+
+    let a = match prompt
         .first
         .prompt_once(&mut read, &mut write, &fmt.a_rules)?
-    else {
-        return Ok(ControlFlow::Continue(()));
+    {
+        ControlFlow::Break(a) => a,
+        ControlFlow::Continue(rejection) => return Ok(ControlFlow::Continue(rejection)),
     };
 
-    let b = loop {
-        if let ControlFlow::Break(b) =
-            prompt
-                .then
-                .prompt_once(&mut read, &mut write, &fmt.b_rules)?
-        {
-            break b;
-        }
-    };
+    let b = crate::prompt_with_fmt(&mut prompt.then, read, write, &fmt.b_rules)?;
 
     Ok(ControlFlow::Break(FromOutput::from_output((a, b))))
 }
 
+#[cfg(feature = "async")]
+#[cfg_attr(nightly, doc(cfg(feature = "async")))]
+impl<A, B, O> crate::AsyncPromptable for Then<A, B, O>
+where
+    A: crate::AsyncPromptable,
+    B: crate::AsyncPromptable,
+    O: FromOutput<<Self as Flattenable>::RawOutput>,
+{
+    async fn prompt_once_async<R, W>(
+        &mut self, mut read: R, mut write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<O, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        let fmt = fmt.root();
+
+        let a = match self
+            .first
+            .prompt_once_async(&mut read, &mut write, &fmt.a_rules)
+            .await?
+        {
+            ControlFlow::Break(a) => a,
+            ControlFlow::Continue(rejection) => return Ok(ControlFlow::Continue(rejection)),
+        };
+
+        let b = crate::prompt_with_fmt_async(&mut self.then, read, write, &fmt.b_rules).await?;
+
+        Ok(ControlFlow::Break(FromOutput::from_output((a, b))))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
@@ -159,4 +199,147 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn eof_input() {
+        let input = "foobar\n3\n".as_bytes();
+        let res: std::io::Result<(String, i32)> = crate::written::<String>("")
+            .then(crate::selected("", [("", 1000), ("", 2000)]))
+            .prompt_with(input, std::io::empty());
+        assert_eq!(res.unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+
+        let input = "foobar\n1\n".as_bytes();
+        let res: std::io::Result<(String, i32, bool)> = crate::written::<String>("")
+            .then(crate::selected("", [("", 1000), ("", 2000)]))
+            .then(crate::bool(""))
+            .prompt_with(input, std::io::empty());
+        assert_eq!(res.unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn print_rejections() -> anyhow::Result<()> {
+        let input = "foo\n1\nfoo\n2\n".as_bytes();
+        let mut output = Vec::new();
+        let (a, b) = crate::written::<i32>("a")
+            .then(crate::written::<i32>("b").fmt(crate::fmt().err_prefix("x ")))
+            .fmt(
+                crate::fmt()
+                    .break_line(false)
+                    .msg_prefix("")
+                    .input_prefix(": "),
+            )
+            .prompt_with(input, &mut output)?;
+
+        assert_eq!((a, b), (1, 2));
+        assert_eq!(
+            String::from_utf8(output)?,
+            "a: ! invalid digit found in string\n: b: x invalid digit found in string\n: "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn default_values_on_restart() -> anyhow::Result<()> {
+        let input = "\n0\n\n2\n".as_bytes();
+        let (a, b) = crate::written::<i32>("")
+            .default(1)
+            .then(crate::written::<i32>(""))
+            .until(|(a, b)| a < b)
+            .prompt_with(input, std::io::empty())?;
+
+        assert_eq!((a, b), (1, 2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn step_prefix() -> anyhow::Result<()> {
+        let input = "foo\n1\n1\nfoo\n3\n".as_bytes();
+        let mut output = Vec::new();
+        let (a, b, c) = crate::written::<i32>("a")
+            .then(crate::selected("b", [("x", 2)]))
+            .then(crate::written::<i32>("c"))
+            .fmt(
+                crate::fmt()
+                    .step_prefix("{step}/{total} ({attempt}) ")
+                    .msg_prefix("")
+                    .input_prefix(": ")
+                    .break_line(false)
+                    .repeat_prompt(true),
+            )
+            .prompt_with(input, &mut output)?;
+
+        assert_eq!((a, b, c), (1, 2, 3));
+        assert_eq!(
+            String::from_utf8(output)?,
+            "1/3 (1) a: ! invalid digit found in string\n\
+            1/3 (2) a: [1] - x\n\
+            2/3 (1) b: \
+            3/3 (1) c: ! invalid digit found in string\n\
+            3/3 (2) c: "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn nested_step_prefix() -> anyhow::Result<()> {
+        let input = "1\n2\n3\n4\n".as_bytes();
+        let mut output = Vec::new();
+        let (a, (b, c), d): (i32, (i32, i32), i32) = crate::written::<i32>("a")
+            .then(
+                crate::written::<i32>("b")
+                    .then(crate::written::<i32>("c"))
+                    .fmt(crate::fmt().msg_prefix("> ")),
+            )
+            .then(crate::written::<i32>("d"))
+            .fmt(
+                crate::fmt()
+                    .step_prefix("[{step}/{total}] ")
+                    .msg_prefix("")
+                    .input_prefix("\n"),
+            )
+            .prompt_with(input, &mut output)?;
+
+        assert_eq!((a, b, c, d), (1, 2, 3, 4));
+        assert_eq!(
+            String::from_utf8(output)?,
+            "[1/4] a\n\n> [2/4] b\n\n> [3/4] c\n\n[4/4] d\n\n"
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_any_invalid_input() -> anyhow::Result<()> {
+        use tokio::io::{AsyncReadExt as _, AsyncWriteExt as _, BufReader};
+
+        let (mut input, read) = tokio::io::duplex(1024);
+        let (write, mut output) = tokio::io::duplex(1024);
+        input.write_all(b"foo\n1\nfoo\n2\n").await?;
+        drop(input);
+
+        let (a, b) = crate::written::<i32>("a")
+            .then(crate::written::<i32>("b").fmt(crate::fmt().err_prefix("x ")))
+            .fmt(
+                crate::fmt()
+                    .break_line(false)
+                    .msg_prefix("")
+                    .input_prefix(": "),
+            )
+            .prompt_with_async(BufReader::new(read), write)
+            .await?;
+        assert_eq!((a, b), (1, 2));
+
+        let mut s = String::new();
+        output.read_to_string(&mut s).await?;
+        assert_eq!(
+            s,
+            "a: ! invalid digit found in string\n: b: x invalid digit found in string\n: "
+        );
+
+        Ok(())
+    }
 }
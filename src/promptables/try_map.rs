@@ -0,0 +1,133 @@
+use std::{fmt::Display, io, ops::ControlFlow};
+
+use crate::{Promptable, Rejection};
+
+/// Wrapper for promptable types to map the output into another value, or to reject it.
+///
+/// See the [`Promptable::try_map()`] method for more information.
+#[derive(Clone)]
+pub struct TryMap<P, F> {
+    pub(crate) prompt: P,
+    pub(crate) map: F,
+}
+
+impl<P, F, T, E> Promptable for TryMap<P, F>
+where
+    P: Promptable,
+    F: FnMut(<P as Promptable>::Output) -> Result<T, E>,
+    E: Display,
+{
+    type Output = T;
+    type FmtRules = <P as Promptable>::FmtRules;
+
+    fn prompt_once<R, W>(
+        &mut self, read: R, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: io::BufRead,
+        W: io::Write,
+    {
+        self.prompt
+            .prompt_once(read, write, fmt)
+            .map(|flow| self.map_flow(flow, fmt))
+    }
+}
+
+impl<P, F> TryMap<P, F> {
+    fn map_flow<O, T, E>(
+        &mut self, flow: ControlFlow<O, Rejection>, fmt: &<P as Promptable>::FmtRules,
+    ) -> ControlFlow<T, Rejection>
+    where
+        P: Promptable,
+        F: FnMut(O) -> Result<T, E>,
+        E: Display,
+    {
+        match flow {
+            ControlFlow::Break(val) => match (self.map)(val) {
+                Ok(val) => ControlFlow::Break(val),
+                Err(err) => ControlFlow::Continue(Rejection::new(err, fmt)),
+            },
+            ControlFlow::Continue(rejection) => ControlFlow::Continue(rejection),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(nightly, doc(cfg(feature = "async")))]
+impl<P, F, T, E> crate::AsyncPromptable for TryMap<P, F>
+where
+    P: crate::AsyncPromptable,
+    F: FnMut(<P as Promptable>::Output) -> Result<T, E>,
+    E: Display,
+{
+    async fn prompt_once_async<R, W>(
+        &mut self, read: R, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        let flow = self.prompt.prompt_once_async(read, write, fmt).await?;
+        Ok(self.map_flow(flow, fmt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn basic() -> anyhow::Result<()> {
+        let input = "foo\n-3\n3\n".as_bytes();
+        let res = crate::written::<i32>("")
+            .try_map(u32::try_from)
+            .prompt_with(input, std::io::empty())?;
+        assert_eq!(res, 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn eof_input() {
+        let res = crate::written::<i32>("")
+            .try_map(u32::try_from)
+            .prompt_with("-3\n".as_bytes(), std::io::empty());
+        assert_eq!(res.unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn error_msg() -> anyhow::Result<()> {
+        let input = "foo\nfoo@bar\n".as_bytes();
+        let mut output = Vec::new();
+        let (user, host) = crate::written::<String>("Email")
+            .try_map(|email| match email.split_once('@') {
+                Some((user, host)) => Ok((user.to_owned(), host.to_owned())),
+                None => Err(format!("{email:?} is missing an @")),
+            })
+            .fmt(crate::fmt().inline().err_prefix("x "))
+            .prompt_with(input, &mut output)?;
+        assert_eq!((user.as_str(), host.as_str()), ("foo", "bar"));
+        assert_eq!(
+            String::from_utf8(output)?,
+            "Email: x \"foo\" is missing an @\nEmail: "
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_basic() -> anyhow::Result<()> {
+        let (mut input, read) = tokio::io::duplex(1024);
+        tokio::io::AsyncWriteExt::write_all(&mut input, b"-3\n3\n").await?;
+        drop(input);
+
+        let res = crate::written::<i32>("")
+            .try_map(u32::try_from)
+            .prompt_with_async(tokio::io::BufReader::new(read), tokio::io::sink())
+            .await?;
+        assert_eq!(res, 3);
+
+        Ok(())
+    }
+}
@@ -1,13 +1,37 @@
 use std::{io, ops::ControlFlow};
 
-use crate::Promptable;
+use crate::{Promptable, Rejection};
 
 /// Wrapper for promptable types to add a validator on the output.
 ///
 /// See the [`Promptable::until()`] method for more information.
+#[derive(Clone)]
 pub struct Until<P, F> {
     pub(crate) prompt: P,
     pub(crate) until: F,
+    pub(crate) msg: Option<String>,
+}
+
+/// The default reason of the rejection when the output doesn't pass the validator.
+const INVALID_INPUT: &str = "invalid input";
+
+impl<P, F> Until<P, F> {
+    /// Sets the message displayed to the user when the output is rejected by the validator.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ineed::prelude::*;
+    /// let port = ineed::written::<u16>("Port")
+    ///   .until(|port| *port >= 1024)
+    ///   .msg("the port must not be a well-known port")
+    ///   .prompt()
+    ///   .unwrap();
+    /// ```
+    pub fn msg<M: Into<String>>(mut self, msg: M) -> Self {
+        self.msg = Some(msg.into());
+        self
+    }
 }
 
 impl<P, F> Promptable for Until<P, F>
@@ -20,17 +44,53 @@ where
 
     fn prompt_once<R, W>(
         &mut self, read: R, write: W, fmt: &Self::FmtRules,
-    ) -> io::Result<ControlFlow<Self::Output>>
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
     where
         R: io::BufRead,
         W: io::Write,
     {
         self.prompt
             .prompt_once(read, write, fmt)
-            .map(|flow| match flow {
-                ControlFlow::Break(val) if (self.until)(&val) => ControlFlow::Break(val),
-                _ => ControlFlow::Continue(()),
-            })
+            .map(|flow| self.check(flow, fmt))
+    }
+}
+
+impl<P, F> Until<P, F>
+where
+    P: Promptable,
+    F: FnMut(&<P as Promptable>::Output) -> bool,
+{
+    fn check(
+        &mut self, flow: ControlFlow<<P as Promptable>::Output, Rejection>,
+        fmt: &<P as Promptable>::FmtRules,
+    ) -> ControlFlow<<P as Promptable>::Output, Rejection> {
+        match flow {
+            ControlFlow::Break(val) if (self.until)(&val) => ControlFlow::Break(val),
+            ControlFlow::Break(_) => ControlFlow::Continue(Rejection::new(
+                self.msg.as_deref().unwrap_or(INVALID_INPUT),
+                fmt,
+            )),
+            ControlFlow::Continue(rejection) => ControlFlow::Continue(rejection),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(nightly, doc(cfg(feature = "async")))]
+impl<P, F> crate::AsyncPromptable for Until<P, F>
+where
+    P: crate::AsyncPromptable,
+    F: FnMut(&<P as Promptable>::Output) -> bool,
+{
+    async fn prompt_once_async<R, W>(
+        &mut self, read: R, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        let flow = self.prompt.prompt_once_async(read, write, fmt).await?;
+        Ok(self.check(flow, fmt))
     }
 }
 
@@ -48,4 +108,49 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn eof_input() {
+        let input = "3\n4\n".as_bytes();
+        let res = crate::written::<u32>("")
+            .until(|x| *x > 9)
+            .prompt_with(input, std::io::empty());
+        assert_eq!(res.unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn custom_msg() -> anyhow::Result<()> {
+        let input = "3\n10\n".as_bytes();
+        let mut output = Vec::new();
+        let res = crate::written::<u32>("foo")
+            .until(|x| *x > 9)
+            .msg("too small")
+            .fmt(
+                crate::fmt()
+                    .break_line(false)
+                    .msg_prefix("")
+                    .input_prefix(": "),
+            )
+            .prompt_with(input, &mut output)?;
+        assert_eq!(res, 10);
+        assert_eq!(String::from_utf8(output)?, "foo: ! too small\n: ");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_basic() -> anyhow::Result<()> {
+        let (mut input, read) = tokio::io::duplex(1024);
+        tokio::io::AsyncWriteExt::write_all(&mut input, b"aa\n3\n10\n").await?;
+        drop(input);
+
+        let res = crate::written::<u32>("")
+            .until(|x| *x > 9)
+            .prompt_with_async(tokio::io::BufReader::new(read), tokio::io::sink())
+            .await?;
+        assert_eq!(res, 10);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,112 @@
+use std::{fmt::Display, io, ops::ControlFlow};
+
+use crate::{Promptable, Rejection};
+
+/// Wrapper for promptable types to add a validator with custom error messages on the output.
+///
+/// See the [`Promptable::validate()`] method for more information.
+#[derive(Clone)]
+pub struct Validate<P, F> {
+    pub(crate) prompt: P,
+    pub(crate) validate: F,
+}
+
+impl<P, F, E> Promptable for Validate<P, F>
+where
+    P: Promptable,
+    F: FnMut(&<P as Promptable>::Output) -> Result<(), E>,
+    E: Display,
+{
+    type Output = <P as Promptable>::Output;
+    type FmtRules = <P as Promptable>::FmtRules;
+
+    fn prompt_once<R, W>(
+        &mut self, read: R, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: io::BufRead,
+        W: io::Write,
+    {
+        self.prompt
+            .prompt_once(read, write, fmt)
+            .map(|flow| self.check(flow, fmt))
+    }
+}
+
+impl<P, F, E> Validate<P, F>
+where
+    P: Promptable,
+    F: FnMut(&<P as Promptable>::Output) -> Result<(), E>,
+    E: Display,
+{
+    fn check(
+        &mut self, flow: ControlFlow<<P as Promptable>::Output, Rejection>,
+        fmt: &<P as Promptable>::FmtRules,
+    ) -> ControlFlow<<P as Promptable>::Output, Rejection> {
+        match flow {
+            ControlFlow::Break(val) => match (self.validate)(&val) {
+                Ok(()) => ControlFlow::Break(val),
+                Err(err) => ControlFlow::Continue(Rejection::new(err, fmt)),
+            },
+            ControlFlow::Continue(rejection) => ControlFlow::Continue(rejection),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(nightly, doc(cfg(feature = "async")))]
+impl<P, F, E> crate::AsyncPromptable for Validate<P, F>
+where
+    P: crate::AsyncPromptable,
+    F: FnMut(&<P as Promptable>::Output) -> Result<(), E>,
+    E: Display,
+{
+    async fn prompt_once_async<R, W>(
+        &mut self, read: R, write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        let flow = self.prompt.prompt_once_async(read, write, fmt).await?;
+        Ok(self.check(flow, fmt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn error_msg() -> anyhow::Result<()> {
+        let input = "80\n8080\n".as_bytes();
+        let mut output = Vec::new();
+        let res = crate::written::<u16>("Port")
+            .validate(|port| match port {
+                ..1024 => Err(format!("{port} is a well-known port")),
+                _ => Ok(()),
+            })
+            .fmt(
+                crate::fmt()
+                    .break_line(false)
+                    .msg_prefix("")
+                    .input_prefix(": "),
+            )
+            .prompt_with(input, &mut output)?;
+        assert_eq!(res, 8080);
+        assert_eq!(
+            String::from_utf8(output)?,
+            "Port: ! 80 is a well-known port\n: "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn eof_input() {
+        let res = crate::written::<u16>("")
+            .validate(|port| if *port < 1024 { Err("nope") } else { Ok(()) })
+            .prompt_with("80\n".as_bytes(), std::io::empty());
+        assert_eq!(res.unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+}
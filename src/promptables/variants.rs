@@ -0,0 +1,221 @@
+use std::{io, ops::ControlFlow};
+
+use crate::{LabelMatch, Promptable, Rejection, Selected, format::rules::SelectedFmtRules};
+
+/// The function prompting the payload of a [`Variant`].
+///
+/// It is given the input and output streams of the prompt, and the format rules of the
+/// [`Variants`] promptable. It must prompt the user once, usually with the
+/// [`Promptable::prompt_once`] method, so the rejections of the payload are handled like the
+/// ones of the list.
+pub type PayloadPrompt<T> = for<'r, 'fmt> fn(
+    &'r mut dyn io::BufRead,
+    &'r mut dyn io::Write,
+    &'r SelectedFmtRules<'fmt>,
+) -> io::Result<ControlFlow<T, Rejection>>;
+
+/// An item of the list given to the [`variants()`] function.
+#[derive(Clone)]
+pub enum Variant<T> {
+    /// The value is returned as is when the item is selected.
+    Value(T),
+    /// The value is built from another prompt when the item is selected.
+    Payload(PayloadPrompt<T>),
+}
+
+/// Promptable type for selectable inputs that can require another prompt.
+///
+/// See the [`variants()`] function for more information.
+#[derive(Clone)]
+pub struct Variants<'a, 'fmt, const N: usize, T> {
+    inner: Selected<'a, 'fmt, N, Variant<T>>,
+    /// The payload prompt of the selected item, if its previous input was rejected.
+    payload: Option<PayloadPrompt<T>>,
+}
+
+impl<const N: usize, T> Variants<'_, '_, N, T> {
+    /// Gives a default item to the prompt, selected when the user enters an empty input.
+    ///
+    /// See the [`Selected::default`] method for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds of the list.
+    pub fn default(self, index: usize) -> Self {
+        Self {
+            inner: self.inner.default(index),
+            ..self
+        }
+    }
+
+    /// Sets the way the user input is matched against the labels of the items.
+    ///
+    /// See the [`Selected::label_match`] method for more information.
+    pub fn label_match(self, label_match: LabelMatch) -> Self {
+        Self {
+            inner: self.inner.label_match(label_match),
+            ..self
+        }
+    }
+
+    /// Enables the fuzzy filtering of the items, to search them in long lists.
+    ///
+    /// See the [`Selected::fuzzy`] method for more information.
+    pub fn fuzzy(self, fuzzy: bool) -> Self {
+        Self {
+            inner: self.inner.fuzzy(fuzzy),
+            ..self
+        }
+    }
+}
+
+impl<'fmt, const N: usize, T> Promptable for Variants<'_, 'fmt, N, T> {
+    type Output = T;
+    type FmtRules = SelectedFmtRules<'fmt>;
+
+    fn prompt_once<R, W>(
+        &mut self, mut read: R, mut write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: io::BufRead,
+        W: io::Write,
+    {
+        let prompt = match self.payload {
+            Some(prompt) => prompt,
+            None => match self.inner.prompt_once(&mut read, &mut write, fmt)? {
+                ControlFlow::Break(Variant::Value(out)) => return Ok(ControlFlow::Break(out)),
+                ControlFlow::Break(Variant::Payload(prompt)) => prompt,
+                ControlFlow::Continue(rejection) => return Ok(ControlFlow::Continue(rejection)),
+            },
+        };
+
+        let out = prompt(&mut read, &mut write, fmt)?;
+        self.payload = out.is_continue().then_some(prompt);
+        Ok(out)
+    }
+}
+
+/// Returns a type that prompts the user a selectable value, which can require another prompt.
+///
+/// This works like the [`selected()`](crate::selected) function, but each item of the list is
+/// either a [value](Variant::Value), or a [function](Variant::Payload) that prompts the user
+/// for the value once the item is selected. This is mostly used by the `Selectable` derive macro,
+/// for enum variants with fields.
+///
+/// The payload prompt is given the format rules of this promptable. If its input is rejected, the
+/// rejection is returned like the ones of the list, and the next try prompts the payload again
+/// instead of the list.
+///
+/// # Example
+///
+/// ```no_run
+/// # use ineed::prelude::*;
+/// enum Level {
+///   Good,
+///   Custom(u8),
+/// }
+///
+/// let level = ineed::variants(
+///   "Your level",
+///   [
+///     ("Good", ineed::Variant::Value(Level::Good)),
+///     ("Custom", ineed::Variant::Payload(|read, write, fmt| {
+///       ineed::written::<u8>("Custom level")
+///         .map(Level::Custom)
+///         .prompt_once(read, write, &(*fmt).into())
+///     })),
+///   ],
+/// )
+///   .prompt()
+///   .unwrap();
+/// ```
+pub fn variants<'a, 'fmt, const N: usize, T>(
+    title: &'a str, list: [(&'a str, Variant<T>); N],
+) -> Variants<'a, 'fmt, N, T> {
+    Variants {
+        inner: crate::selected(title, list),
+        payload: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::ControlFlow;
+
+    use crate::{Rejection, Variant, format::rules::SelectedFmtRules, prelude::*};
+
+    #[derive(Debug, PartialEq)]
+    enum Level {
+        Good,
+        Custom(u8),
+    }
+
+    fn custom(
+        read: &mut dyn std::io::BufRead, write: &mut dyn std::io::Write, fmt: &SelectedFmtRules<'_>,
+    ) -> std::io::Result<ControlFlow<Level, Rejection>> {
+        crate::written::<u8>("Custom")
+            .map(Level::Custom)
+            .prompt_once(read, write, &(*fmt).into())
+    }
+
+    #[test]
+    fn value_and_payload() -> anyhow::Result<()> {
+        let list = || {
+            [
+                ("Good", Variant::Value(Level::Good)),
+                ("Custom", Variant::Payload(custom)),
+            ]
+        };
+
+        let res = crate::variants("", list()).prompt_with("1\n".as_bytes(), std::io::empty())?;
+        assert_eq!(res, Level::Good);
+
+        let input = "3\n2\nfoo\n42\n".as_bytes();
+        let mut output = Vec::new();
+        let res = crate::variants("Level", list()).prompt_with(input, &mut output)?;
+        assert_eq!(res, Level::Custom(42));
+        assert_eq!(
+            String::from_utf8(output)?,
+            "[1] - Good\n[2] - Custom\n- Level\n> ! choose a number between 1 and 2\n> \
+             - Custom\n> ! invalid digit found in string\n- Custom\n> "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn payload_with_enclosing_rules() -> anyhow::Result<()> {
+        let input = "2\n1\nfoo\n42\n".as_bytes();
+        let mut output = Vec::new();
+        let res = crate::variants("Level", [("Custom", Variant::Payload(custom))])
+            .fmt(
+                crate::fmt()
+                    .inline()
+                    .list_surrounds("", ". ")
+                    .step_prefix("#{attempt} "),
+            )
+            .max_tries(2)
+            .prompt_with(input, &mut output)?;
+        assert!(res.is_err());
+        assert_eq!(
+            String::from_utf8(output)?,
+            "1. Custom\n#1 Level: ! choose a number between 1 and 1\n\
+            #2 Level: #2 Custom: ! invalid digit found in string\n"
+        );
+
+        let input = "1\nfoo\n42\n".as_bytes();
+        let res = crate::variants("Level", [("Custom", Variant::Payload(custom))])
+            .max_tries(2)
+            .prompt_with(input, std::io::empty())?;
+        assert_eq!(res.ok(), Some(Level::Custom(42)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn eof_in_payload() {
+        let res = crate::variants("", [("Custom", Variant::Payload(custom))])
+            .prompt_with("1\n".as_bytes(), std::io::empty());
+        assert!(matches!(res, Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof));
+    }
+}
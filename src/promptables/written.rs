@@ -1,9 +1,15 @@
-use std::{io, marker::PhantomData, ops::ControlFlow, str::FromStr};
+use std::{fmt::Display, io, marker::PhantomData, ops::ControlFlow, str::FromStr};
 
-use crate::{Promptable, WrittenFmtRules, format::Partial as _};
+use crate::{
+    Promptable, Rejection, WrittenFmtRules,
+    format::{self, Partial as _},
+};
 
+#[derive(Clone)]
 pub(crate) struct WrittenInner<'a, 'fmt> {
     msg: Option<&'a str>,
+    default: Option<String>,
+    default_labelled: bool,
     _marker: PhantomData<&'fmt ()>,
 }
 
@@ -11,18 +17,32 @@ impl<'a> WrittenInner<'a, '_> {
     pub(crate) fn new(msg: &'a str) -> Self {
         Self {
             msg: Some(msg),
+            default: None,
+            default_labelled: false,
             _marker: PhantomData,
         }
     }
 
-    pub(crate) fn prompt_with<R, W, F>(
-        &mut self, mut read: R, mut write: W, fmt: &WrittenFmtRules<'_>, f: F,
-    ) -> io::Result<String>
-    where
-        R: io::BufRead,
-        W: io::Write,
-        F: FnOnce(&mut R) -> io::Result<String>,
-    {
+    /// Sets the default value displayed between the default surrounds, right after the message.
+    ///
+    /// It's preceded by the [`default_label`](format::FmtRule::default_label) format rule.
+    pub(crate) fn set_default(&mut self, default: String) {
+        self.default = Some(default);
+        self.default_labelled = true;
+    }
+
+    /// Sets the hint displayed between the default surrounds, right after the message.
+    ///
+    /// Unlike the default value, the hint isn't labelled.
+    pub(crate) fn set_default_hint(&mut self, hint: String) {
+        self.default = Some(hint);
+        self.default_labelled = false;
+    }
+
+    /// Writes the message, if it must be displayed, and the input prefix.
+    fn write_prompt<W: io::Write>(
+        &mut self, mut write: W, fmt: &WrittenFmtRules<'_>,
+    ) -> io::Result<()> {
         let fmt = fmt.expand();
 
         if let Some(msg) = if fmt.repeat_prompt {
@@ -30,14 +50,40 @@ impl<'a> WrittenInner<'a, '_> {
         } else {
             self.msg.take()
         } {
-            write!(write, "{}{msg}", fmt.msg_prefix)?;
+            let step = format::fill_step_prefix(fmt.step_prefix, fmt.step, fmt.attempt);
+            let msg = format_args!("{}{step}{msg}", fmt.msg_prefix);
+            write!(write, "{}", fmt.msg_style.paint(msg))?;
+
+            if let Some(default) = &self.default {
+                let (open, close) = fmt.default_surrounds;
+                let label = if self.default_labelled {
+                    fmt.default_label
+                } else {
+                    ""
+                };
+                write!(write, "{open}{label}{default}{close}")?;
+            }
+            if !fmt.msg_suffix.is_empty() {
+                write!(write, "{}", fmt.msg_style.paint(fmt.msg_suffix))?;
+            }
 
             if fmt.break_line {
                 writeln!(write)?;
             }
         }
 
-        write!(write, "{}", fmt.input_prefix)?;
+        write!(write, "{}", fmt.input_style.paint(fmt.input_prefix))
+    }
+
+    pub(crate) fn prompt_with<R, W, F>(
+        &mut self, mut read: R, mut write: W, fmt: &WrittenFmtRules<'_>, f: F,
+    ) -> io::Result<String>
+    where
+        R: io::BufRead,
+        W: io::Write,
+        F: FnOnce(&mut R) -> io::Result<String>,
+    {
+        self.write_prompt(&mut write, fmt)?;
         write.flush()?;
 
         Ok(f(&mut read)?.trim().to_owned())
@@ -50,20 +96,66 @@ impl<'a> WrittenInner<'a, '_> {
         R: io::BufRead,
         W: io::Write,
     {
-        self.prompt_with(read, write, fmt, |read| {
-            let mut s = String::new();
-            read.read_line(&mut s)?;
-            Ok(s)
-        })
+        self.prompt_with(read, write, fmt, crate::read_line)
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) async fn prompt_async<R, W>(
+        &mut self, read: &mut R, write: &mut W, fmt: &WrittenFmtRules<'_>,
+    ) -> io::Result<String>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        let mut buf = Vec::new();
+        self.write_prompt(&mut buf, fmt)?;
+        crate::write_all_async(write, &buf).await?;
+
+        Ok(crate::read_line_async(read).await?.trim().to_owned())
     }
 }
 
 /// Promptable type for written inputs.
 ///
 /// See the [`written()`] function for more information.
+#[derive(Clone)]
 pub struct Written<'a, 'fmt, T> {
     inner: WrittenInner<'a, 'fmt>,
-    _marker: PhantomData<T>,
+    default: Option<DefaultValue<T>>,
+}
+
+/// A default value, along with the function to clone it.
+///
+/// This avoids requiring the output type to be [`Clone`], unless a default value is provided.
+pub(crate) type DefaultValue<T> = (T, fn(&T) -> T);
+
+impl<T> Written<'_, '_, T> {
+    /// Gives a default value to the prompt, returned when the user enters an empty input.
+    ///
+    /// The default value is displayed right after the message, with the
+    /// [`default_surrounds`](crate::format::FmtRule::default_surrounds) and
+    /// [`default_label`](crate::format::FmtRule::default_label) format rules.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ineed::prelude::*;
+    /// let port = ineed::written::<u16>("Port").default(8080).prompt().unwrap();
+    /// ```
+    ///
+    /// The above example will show something similar to this:
+    /// ```txt
+    /// - Port [default: 8080]
+    /// >
+    /// ```
+    pub fn default(mut self, value: T) -> Self
+    where
+        T: Display + Clone,
+    {
+        self.inner.set_default(value.to_string());
+        self.default = Some((value, T::clone));
+        self
+    }
 }
 
 /// Returns a type that prompts the user for a written input.
@@ -71,7 +163,8 @@ pub struct Written<'a, 'fmt, T> {
 /// The output value is of type `T`, which must implement [`FromStr`].
 ///
 /// The promptable checks for each try that the entered text is valid, meaning [`FromStr::from_str`]
-/// returned `Ok(_)`.
+/// returned `Ok(_)`. Otherwise, the error returned by [`FromStr::from_str`] is displayed as the
+/// reason of the rejection.
 ///
 /// # Example
 ///
@@ -85,7 +178,9 @@ pub struct Written<'a, 'fmt, T> {
 /// ```txt
 /// - Your age
 /// > no
+/// ! invalid digit found in string
 /// > foobar
+/// ! invalid digit found in string
 /// > 3
 /// ```
 ///
@@ -111,7 +206,9 @@ pub struct Written<'a, 'fmt, T> {
 /// Let's take the same example of tries from the user above, the display would be like this:
 /// ```txt
 /// - Your age: no
+/// ! invalid digit found in string
 /// - Your age: foobar
+/// ! invalid digit found in string
 /// - Your age: 3
 /// ```
 ///
@@ -121,29 +218,68 @@ pub struct Written<'a, 'fmt, T> {
 pub fn written<T>(msg: &str) -> Written<'_, '_, T> {
     Written {
         inner: WrittenInner::new(msg),
-        _marker: PhantomData,
+        default: None,
+    }
+}
+
+/// The reason of the rejection when the user entered nothing.
+pub(crate) const EMPTY_INPUT: &str = "please enter a value";
+
+impl<T> Written<'_, '_, T>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+    fn parse(&self, input: &str, fmt: &WrittenFmtRules<'_>) -> ControlFlow<T, Rejection> {
+        if input.is_empty() {
+            return match &self.default {
+                Some((default, clone)) => ControlFlow::Break(clone(default)),
+                None => ControlFlow::Continue(Rejection::new(EMPTY_INPUT, fmt)),
+            };
+        }
+        match input.parse() {
+            Ok(out) => ControlFlow::Break(out),
+            Err(e) => ControlFlow::Continue(Rejection::new(e, fmt)),
+        }
     }
 }
 
 impl<'fmt, T> Promptable for Written<'_, 'fmt, T>
 where
     T: FromStr,
+    <T as FromStr>::Err: Display,
 {
     type Output = T;
     type FmtRules = WrittenFmtRules<'fmt>;
 
     fn prompt_once<R, W>(
         &mut self, read: R, write: W, fmt: &Self::FmtRules,
-    ) -> io::Result<ControlFlow<Self::Output>>
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
     where
         R: io::BufRead,
         W: io::Write,
     {
         let input = self.inner.prompt(read, write, fmt)?;
-        match input.parse() {
-            Ok(out) if !input.is_empty() => Ok(ControlFlow::Break(out)),
-            _ => Ok(ControlFlow::Continue(())),
-        }
+        Ok(self.parse(&input, fmt))
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(nightly, doc(cfg(feature = "async")))]
+impl<T> crate::AsyncPromptable for Written<'_, '_, T>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+    async fn prompt_once_async<R, W>(
+        &mut self, mut read: R, mut write: W, fmt: &Self::FmtRules,
+    ) -> io::Result<ControlFlow<Self::Output, Rejection>>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        let input = self.inner.prompt_async(&mut read, &mut write, fmt).await?;
+        Ok(self.parse(&input, fmt))
     }
 }
 
@@ -207,7 +343,7 @@ mod tests {
 
         let default_fmt = WrittenFmtRules::default().expand();
         let expected_msg = format!(
-            "{0}googa{1}{2}{3}{3}{3}{3}",
+            "{0}googa{1}{2}{4}{3}{4}{3}{4}{3}{4}{3}",
             default_fmt.msg_prefix,
             if default_fmt.break_line { "\n" } else { "" },
             default_fmt.input_prefix,
@@ -219,12 +355,37 @@ mod tests {
                     default_fmt.msg_prefix, default_fmt.input_prefix
                 )
             },
+            format!("{}invalid digit found in string\n", default_fmt.err_prefix),
         );
         assert_eq!(String::from_utf8(output)?, expected_msg);
 
         Ok(())
     }
 
+    #[test]
+    fn empty_input() -> anyhow::Result<()> {
+        let input = b"\n  \nhello\n";
+        let mut output = Vec::new();
+
+        let res = crate::written::<String>("booga")
+            .fmt(
+                crate::fmt()
+                    .break_line(false)
+                    .msg_prefix("* ")
+                    .input_prefix(": ")
+                    .err_prefix("x "),
+            )
+            .prompt_with(input.as_slice(), &mut output)?;
+
+        assert_eq!(res, "hello");
+        assert_eq!(
+            String::from_utf8(output)?.as_str(),
+            "* booga: x please enter a value\n: x please enter a value\n: "
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn fully_customized_fmt_with_good_input() -> anyhow::Result<()> {
         let input = b"hello\n";
@@ -264,9 +425,204 @@ mod tests {
         assert_eq!(res, 2);
         assert_eq!(
             String::from_utf8(output)?.as_str(),
-            "* booga: * booga: * booga: * booga: * booga: "
+            "* booga: ! invalid digit found in string\n\
+            * booga: ! invalid digit found in string\n\
+            * booga: ! invalid digit found in string\n\
+            * booga: ! invalid digit found in string\n\
+            * booga: "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn msg_suffix() -> anyhow::Result<()> {
+        let mut output = Vec::new();
+        let res = crate::written::<String>("booga")
+            .default("hello".to_owned())
+            .fmt(crate::fmt().msg_suffix(" ?"))
+            .prompt_with(b"\n".as_slice(), &mut output)?;
+
+        assert_eq!(res, "hello");
+        assert_eq!(String::from_utf8(output)?, "- booga [default: hello] ?\n> ");
+
+        Ok(())
+    }
+
+    #[test]
+    fn inline_layout() -> anyhow::Result<()> {
+        let mut output = Vec::new();
+        let res = crate::written::<i32>("Age")
+            .fmt(crate::fmt().inline())
+            .prompt_with(b"foo\n42\n".as_slice(), &mut output)?;
+
+        assert_eq!(res, 42);
+        assert_eq!(
+            String::from_utf8(output)?,
+            "Age: ! invalid digit found in string\nAge: "
+        );
+
+        let mut output = Vec::new();
+        crate::written::<i32>("Age")
+            .fmt(crate::fmt().inline().msg_suffix(" = "))
+            .prompt_with(b"42\n".as_slice(), &mut output)?;
+        assert_eq!(String::from_utf8(output)?, "Age = ");
+
+        Ok(())
+    }
+
+    #[test]
+    fn styles() -> anyhow::Result<()> {
+        use crate::format::{Color, Style};
+
+        let fmt = || {
+            crate::fmt()
+                .msg_style(Style::new().bold())
+                .input_style(Style::new().fg(Color::Cyan))
+                .err_style(Style::new().fg(Color::Red))
+        };
+
+        let mut output = Vec::new();
+        crate::written::<i32>("booga")
+            .fmt(fmt())
+            .prompt_with(b"2\n".as_slice(), &mut output)?;
+        assert_eq!(String::from_utf8(output)?, "- booga\n> ");
+
+        let mut output = Vec::new();
+        crate::written::<i32>("booga")
+            .fmt(fmt().styled(true))
+            .prompt_with(b"nop\n2\n".as_slice(), &mut output)?;
+        assert_eq!(
+            String::from_utf8(output)?,
+            "\x1b[1m- booga\x1b[0m\n\x1b[36m> \x1b[0m\
+             \x1b[31m! invalid digit found in string\x1b[0m\n\x1b[36m> \x1b[0m"
         );
 
         Ok(())
     }
+
+    #[test]
+    fn eof_input() {
+        let res =
+            crate::written::<i32>("foo").prompt_with("nop\nnop\n".as_bytes(), std::io::empty());
+        assert_eq!(res.unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+
+        let res = crate::written::<String>("foo").prompt_with("".as_bytes(), std::io::empty());
+        assert_eq!(res.unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn default_value() -> anyhow::Result<()> {
+        let input = b"\n";
+        let mut output = Vec::new();
+
+        let res = crate::written::<u16>("Port")
+            .default(8080)
+            .fmt(
+                crate::fmt()
+                    .msg_prefix("")
+                    .break_line(false)
+                    .input_prefix(": "),
+            )
+            .prompt_with(input.as_slice(), &mut output)?;
+
+        assert_eq!(res, 8080);
+        assert_eq!(String::from_utf8(output)?, "Port [default: 8080]: ");
+
+        let res = crate::written::<u16>("Port")
+            .default(8080)
+            .prompt_with(b"foo\n443\n".as_slice(), std::io::empty())?;
+        assert_eq!(res, 443);
+
+        Ok(())
+    }
+
+    #[test]
+    fn default_value_custom_surrounds() -> anyhow::Result<()> {
+        let mut output = Vec::new();
+
+        crate::written::<String>("Name")
+            .default("foo".to_owned())
+            .fmt(
+                crate::fmt()
+                    .msg_prefix("")
+                    .break_line(false)
+                    .input_prefix(": ")
+                    .default_surrounds(" (", ")"),
+            )
+            .prompt_with(b"\n".as_slice(), &mut output)?;
+
+        assert_eq!(String::from_utf8(output)?, "Name (default: foo): ");
+
+        Ok(())
+    }
+
+    /// A type that can be parsed, but not cloned.
+    #[derive(Debug, PartialEq)]
+    struct Name(String);
+
+    impl std::str::FromStr for Name {
+        type Err = std::convert::Infallible;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Ok(Self(s.to_owned()))
+        }
+    }
+
+    #[test]
+    fn non_clone_output() -> anyhow::Result<()> {
+        let name =
+            crate::written::<Name>("Name").prompt_with("foo\n".as_bytes(), std::io::empty())?;
+        assert_eq!(name, Name("foo".to_owned()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn default_label() -> anyhow::Result<()> {
+        let mut output = Vec::new();
+
+        crate::written::<u16>("Port")
+            .default(8080)
+            .fmt(crate::fmt().inline().default_label("e.g. "))
+            .prompt_with(b"\n".as_slice(), &mut output)?;
+        assert_eq!(String::from_utf8(output)?, "Port [e.g. 8080]: ");
+
+        let mut output = Vec::new();
+        crate::bool("Proceed?")
+            .default(true)
+            .fmt(crate::fmt().inline().default_label("e.g. "))
+            .prompt_with(b"\n".as_slice(), &mut output)?;
+        assert_eq!(String::from_utf8(output)?, "Proceed? [Y/n]: ");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_input() -> anyhow::Result<()> {
+        use tokio::io::{AsyncReadExt as _, AsyncWriteExt as _, BufReader};
+
+        let (mut input, read) = tokio::io::duplex(1024);
+        let (write, mut output) = tokio::io::duplex(1024);
+        input.write_all(b"hello\n23\n").await?;
+        drop(input);
+
+        let res = crate::written::<i32>("googa")
+            .fmt(
+                crate::fmt()
+                    .break_line(false)
+                    .msg_prefix("")
+                    .input_prefix(": "),
+            )
+            .prompt_with_async(BufReader::new(read), write)
+            .await?;
+        assert_eq!(res, 23);
+
+        let mut s = String::new();
+        output.read_to_string(&mut s).await?;
+        assert_eq!(s, "googa: ! invalid digit found in string\n: ");
+
+        Ok(())
+    }
 }
@@ -0,0 +1,382 @@
+//! A terminal backend in raw mode, to select the items of a list with the arrow keys.
+//!
+//! By default, the prompts are line-based: the user enters a line of text, e.g. the index of an
+//! item for selectable prompts. With this backend, the terminal is put in raw mode, so the user
+//! can move a highlighted cursor with the arrow keys, and select an item with Enter.
+//!
+//! This is done with the [`RawPromptable`](crate::RawPromptable) trait:
+//!
+//! ```no_run
+//! # use ineed::prelude::*;
+//! let license = ineed::selected("License", [("MIT", 1), ("GPL", 2), ("BSD", 3)])
+//!   .prompt_raw()
+//!   .unwrap();
+//! ```
+//!
+//! The keys are read from a [`KeySource`]. The [`Terminal`] reads them from the real terminal,
+//! and the [`VirtualTerminal`] feeds scripted key events, which is useful for testing:
+//!
+//! ```
+//! # use ineed::prelude::*;
+//! use ineed::raw::{KeyCode, VirtualTerminal};
+//!
+//! let keys = VirtualTerminal::new().keys([KeyCode::Down, KeyCode::Down, KeyCode::Enter]);
+//! let license = ineed::selected("License", [("MIT", 1), ("GPL", 2), ("BSD", 3)])
+//!   .prompt_raw_with(keys, std::io::sink())
+//!   .unwrap();
+//!
+//! assert_eq!(license, 3);
+//! ```
+
+use std::{
+    collections::VecDeque,
+    io::{self, Write},
+};
+
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyEventKind},
+    queue,
+    style::{Attribute, SetAttribute},
+    terminal::{self, ClearType},
+};
+
+pub use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::{
+    format::{
+        self, Partial as _, Position,
+        rules::{ExpandedSelectedFmtRules, SelectedFmtRules},
+    },
+    promptables::SelectList,
+};
+
+/// Represents a source of key events, read by the prompts in raw mode.
+pub trait KeySource {
+    /// Waits for the next key pressed by the user.
+    fn read_key(&mut self) -> io::Result<KeyEvent>;
+}
+
+impl<K: KeySource + ?Sized> KeySource for &mut K {
+    fn read_key(&mut self) -> io::Result<KeyEvent> {
+        (**self).read_key()
+    }
+}
+
+/// The key source of the real terminal.
+///
+/// The terminal must be in raw mode to receive each key as soon as it's pressed. This is done by
+/// the [`RawPromptable::prompt_raw`](crate::RawPromptable::prompt_raw) method.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Terminal(());
+
+impl Terminal {
+    /// Returns the key source of the real terminal.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl KeySource for Terminal {
+    fn read_key(&mut self) -> io::Result<KeyEvent> {
+        loop {
+            if let Event::Key(key) = event::read()?
+                && key.kind != KeyEventKind::Release
+            {
+                return Ok(key);
+            }
+        }
+    }
+}
+
+/// A virtual terminal, that feeds scripted key events to the prompts.
+///
+/// When there are no more keys, reading a key returns an error of kind
+/// [`UnexpectedEof`](io::ErrorKind::UnexpectedEof).
+#[derive(Debug, Default)]
+pub struct VirtualTerminal {
+    keys: VecDeque<KeyEvent>,
+}
+
+impl VirtualTerminal {
+    /// Returns a new virtual terminal, without any key.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a key to the script.
+    ///
+    /// This accepts a [`KeyCode`] alone, or a [`KeyEvent`] with modifiers.
+    pub fn key<K: Into<KeyEvent>>(mut self, key: K) -> Self {
+        self.keys.push_back(key.into());
+        self
+    }
+
+    /// Adds some keys to the script.
+    pub fn keys<I>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<KeyEvent>,
+    {
+        self.keys.extend(keys.into_iter().map(Into::into));
+        self
+    }
+
+    /// Returns the amount of keys that weren't read by the prompts yet.
+    pub fn remaining_keys(&self) -> usize {
+        self.keys.len()
+    }
+}
+
+impl KeySource for VirtualTerminal {
+    fn read_key(&mut self) -> io::Result<KeyEvent> {
+        self.keys.pop_front().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "there are no more keys in the virtual terminal",
+            )
+        })
+    }
+}
+
+/// Enables the raw mode of the terminal until it's dropped.
+pub(crate) struct RawModeGuard(());
+
+impl RawModeGuard {
+    pub(crate) fn enable() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        Ok(Self(()))
+    }
+}
+
+impl Drop for RawModeGuard {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Prompts the list in raw mode, until the user selects an item with Enter.
+///
+/// The user moves the cursor with the arrow keys (or `k` and `j`), Home and End, and Page Up and
+/// Page Down when the list is paginated. Escape and Ctrl-C cancel the prompt with an error of kind
+/// [`Interrupted`](io::ErrorKind::Interrupted).
+pub(crate) fn select<T, K, W>(
+    list: &mut SelectList<'_, T>, mut keys: K, mut write: W, fmt: &SelectedFmtRules<'_>,
+) -> io::Result<T>
+where
+    K: KeySource,
+    W: Write,
+{
+    if list.len() == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "the list of the selectable items is empty",
+        ));
+    }
+
+    let fmt = fmt.expand();
+    let len = list.len();
+    let page_size = match fmt.page_size {
+        0 => len,
+        size => size,
+    };
+    let mut cursor = list.defaults.first().copied().unwrap_or(0);
+
+    queue!(write, cursor::Hide)?;
+    let res = loop {
+        let lines = write_list(list, &mut write, &fmt, cursor, page_size)?;
+        write.flush()?;
+
+        let key = match keys.read_key() {
+            Ok(key) => key,
+            Err(e) => break Err(e),
+        };
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => cursor = cursor.checked_sub(1).unwrap_or(len - 1),
+            KeyCode::Down | KeyCode::Char('j') => cursor = (cursor + 1) % len,
+            KeyCode::Home => cursor = 0,
+            KeyCode::End => cursor = len - 1,
+            KeyCode::PageUp => cursor = cursor.saturating_sub(page_size),
+            KeyCode::PageDown => cursor = (cursor + page_size).min(len - 1),
+            KeyCode::Enter => break Ok(cursor),
+            KeyCode::Esc => break Err(cancelled()),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                break Err(cancelled());
+            }
+            _ => {}
+        }
+
+        queue!(
+            write,
+            cursor::MoveToColumn(0),
+            cursor::MoveUp(lines),
+            terminal::Clear(ClearType::FromCursorDown),
+        )?;
+    };
+    queue!(write, cursor::Show)?;
+    write.flush()?;
+
+    let index = res?;
+    list.values[index].take().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "the selectable item was already taken",
+        )
+    })
+}
+
+fn cancelled() -> io::Error {
+    io::Error::new(io::ErrorKind::Interrupted, "the prompt was cancelled")
+}
+
+/// Writes the page of the list containing the cursor, with its title.
+///
+/// Returns the amount of lines written.
+fn write_list<T, W: Write>(
+    list: &SelectList<'_, T>, mut write: W, fmt: &ExpandedSelectedFmtRules<'_>, cursor: usize,
+    page_size: usize,
+) -> io::Result<u16> {
+    let (open, close) = fmt.list_surrounds;
+    let page = cursor / page_size;
+    let pages = list.len().div_ceil(page_size);
+    let mut lines = 0;
+
+    if fmt.list_msg_pos == Position::Top {
+        list.write_title(&mut write, fmt)?;
+        write!(write, "\r\n")?;
+        lines += 1;
+    }
+    for i in page * page_size..(page * page_size + page_size).min(list.len()) {
+        if i == cursor {
+            queue!(write, SetAttribute(Attribute::Reverse))?;
+            write!(write, "{open}{}{close}{}", i + 1, list.labels[i])?;
+            queue!(write, SetAttribute(Attribute::Reset))?;
+        } else {
+            let index = format_args!("{open}{}{close}", i + 1);
+            write!(write, "{}{}", fmt.index_style.paint(index), list.labels[i])?;
+        }
+        write!(write, "\r\n")?;
+        lines += 1;
+    }
+    if pages > 1 {
+        let indicator = format::fill_page_indicator(
+            fmt.page_indicator,
+            page + 1,
+            pages,
+            "PgUp: previous, PgDn: next",
+        );
+        write!(write, "{}\r\n", fmt.index_style.paint(indicator))?;
+        lines += 1;
+    }
+    if fmt.list_msg_pos == Position::Bottom {
+        list.write_title(&mut write, fmt)?;
+        write!(write, "\r\n")?;
+        lines += 1;
+    }
+
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KeyCode, KeyEvent, KeyModifiers, VirtualTerminal};
+    use crate::prelude::*;
+
+    fn list() -> [(&'static str, i32); 4] {
+        [("a", 1), ("b", 2), ("c", 3), ("d", 4)]
+    }
+
+    #[test]
+    fn arrow_keys() -> anyhow::Result<()> {
+        let keys = VirtualTerminal::new().keys([
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Up,
+            KeyCode::Enter,
+        ]);
+        let res = crate::selected("", list()).prompt_raw_with(keys, std::io::sink())?;
+        assert_eq!(res, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn wrap_around() -> anyhow::Result<()> {
+        let keys = VirtualTerminal::new().keys([KeyCode::Up, KeyCode::Enter]);
+        let res = crate::selected("", list()).prompt_raw_with(keys, std::io::sink())?;
+        assert_eq!(res, 4);
+
+        let keys = VirtualTerminal::new().keys([KeyCode::End, KeyCode::Down, KeyCode::Enter]);
+        let res = crate::selected("", list()).prompt_raw_with(keys, std::io::sink())?;
+        assert_eq!(res, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn default_item() -> anyhow::Result<()> {
+        let keys = VirtualTerminal::new().keys([KeyCode::Down, KeyCode::Enter]);
+        let res = crate::selected("", list())
+            .default(1)
+            .prompt_raw_with(keys, std::io::sink())?;
+        assert_eq!(res, 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn pages() -> anyhow::Result<()> {
+        let mut keys = VirtualTerminal::new().keys([KeyCode::PageDown, KeyCode::Enter]);
+        let mut output = Vec::new();
+        let res = crate::selected_list("Letter", list())
+            .fmt(crate::fmt().page_size(2))
+            .prompt_raw_with(&mut keys, &mut output)?;
+        assert_eq!(res, 3);
+        assert_eq!(keys.remaining_keys(), 0);
+
+        let output = String::from_utf8(output)?;
+        assert!(output.contains("page 1/2 (PgUp: previous, PgDn: next)\r\n- Letter\r\n"));
+        assert!(output.contains("[4] - d\r\npage 2/2 (PgUp: previous, PgDn: next)\r\n"));
+        assert!(!output.contains("[3] - c\r\n[4] - d\r\n[1]"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn highlighted_cursor() -> anyhow::Result<()> {
+        let keys = VirtualTerminal::new().key(KeyCode::Enter);
+        let mut output = Vec::new();
+        crate::selected("", list()).prompt_raw_with(keys, &mut output)?;
+        let output = String::from_utf8(output)?;
+        assert!(output.contains("\x1b[7m[1] - a\x1b[0m\r\n[2] - b\r\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn cancel() {
+        let keys = VirtualTerminal::new().keys([KeyCode::Down, KeyCode::Esc]);
+        let res = crate::selected("", list()).prompt_raw_with(keys, std::io::sink());
+        assert!(matches!(res, Err(e) if e.kind() == std::io::ErrorKind::Interrupted));
+
+        let keys =
+            VirtualTerminal::new().key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        let res = crate::selected("", list()).prompt_raw_with(keys, std::io::sink());
+        assert!(matches!(res, Err(e) if e.kind() == std::io::ErrorKind::Interrupted));
+    }
+
+    #[test]
+    fn missing_keys() {
+        let keys = VirtualTerminal::new().key(KeyCode::Down);
+        let res = crate::selected("", list()).prompt_raw_with(keys, std::io::sink());
+        assert!(matches!(res, Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof));
+    }
+
+    #[test]
+    fn empty_list() {
+        let keys = VirtualTerminal::new().key(KeyCode::Enter);
+        let res =
+            crate::selected_list::<_, &str, i32>("", []).prompt_raw_with(keys, std::io::sink());
+        assert!(matches!(res, Err(e) if e.kind() == std::io::ErrorKind::InvalidInput));
+    }
+}
@@ -0,0 +1,282 @@
+//! Utilities to test the prompts of your application.
+//!
+//! The [`MockTerminal`] type feeds scripted answers to the prompts, and captures everything that
+//! was rendered to the user. You can then check what the user actually saw:
+//!
+//! ```
+//! # use ineed::prelude::*;
+//! use ineed::testing::MockTerminal;
+//!
+//! let mut term = MockTerminal::new().answer("foo").answer("42");
+//! let age = term.prompt(ineed::written::<u8>("Your age")).unwrap();
+//!
+//! assert_eq!(age, 42);
+//! term.assert_shown("! invalid digit found in string", 1);
+//! term.assert_transcript("- Your age\n> foo\n! invalid digit found in string\n> 42\n");
+//! ```
+
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    fmt::Write as _,
+    io::{self, BufRead, Read, Write},
+};
+
+use crate::Promptable;
+
+/// A mock terminal, that feeds scripted answers to the prompts and captures their output.
+///
+/// Each answer is a line entered by the user. When there are no more answers, the input stream
+/// reaches its end, so the prompt returns an error of kind
+/// [`UnexpectedEof`](io::ErrorKind::UnexpectedEof).
+///
+/// See the [module documentation](self) for more information.
+#[derive(Debug, Default)]
+pub struct MockTerminal {
+    answers: VecDeque<String>,
+    output: Vec<u8>,
+    transcript: RefCell<Vec<u8>>,
+}
+
+impl MockTerminal {
+    /// Returns a new mock terminal, without any answer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an answer to the script.
+    ///
+    /// The answer is a line entered by the user, without the trailing line feed.
+    pub fn answer<A: Into<String>>(mut self, answer: A) -> Self {
+        self.push_answer(answer);
+        self
+    }
+
+    /// Adds some answers to the script.
+    pub fn answers<I>(mut self, answers: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        answers.into_iter().for_each(|a| self.push_answer(a));
+        self
+    }
+
+    /// Adds an answer to the script, without consuming the terminal.
+    pub fn push_answer<A: Into<String>>(&mut self, answer: A) {
+        let mut answer = answer.into();
+        answer.push('\n');
+        self.answers.push_back(answer);
+    }
+
+    /// Returns the amount of answers that weren't read by the prompts yet.
+    pub fn remaining_answers(&self) -> usize {
+        self.answers.len()
+    }
+
+    /// Prompts the provided promptable with the scripted answers, until it's valid.
+    ///
+    /// This calls [`Promptable::prompt_with`] with the answers as input, and captures its output.
+    pub fn prompt<P: Promptable>(&mut self, mut promptable: P) -> io::Result<P::Output> {
+        let read = Input {
+            answers: &mut self.answers,
+            current: Vec::new(),
+            pos: 0,
+            transcript: &self.transcript,
+        };
+        let write = Output {
+            output: &mut self.output,
+            transcript: &self.transcript,
+        };
+        promptable.prompt_with(read, write)
+    }
+
+    /// Returns what the prompts rendered to the user, without the user answers.
+    pub fn output(&self) -> String {
+        String::from_utf8_lossy(&self.output).into_owned()
+    }
+
+    /// Returns what the user saw in the terminal, i.e. the output of the prompts interleaved with
+    /// the answers that were entered.
+    pub fn transcript(&self) -> String {
+        String::from_utf8_lossy(&self.transcript.borrow()).into_owned()
+    }
+
+    /// Returns how many times the provided text was rendered by the prompts.
+    ///
+    /// The answers of the user aren't taken into account.
+    pub fn shown(&self, text: &str) -> usize {
+        self.output().matches(text).count()
+    }
+
+    /// Asserts that the provided text was rendered exactly `times` times by the prompts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the text was rendered a different amount of times.
+    #[track_caller]
+    pub fn assert_shown(&self, text: &str, times: usize) {
+        let shown = self.shown(text);
+        if shown != times {
+            panic!(
+                "{text:?} was shown {shown} time(s) instead of {times}\n{}",
+                self.fmt_transcript()
+            );
+        }
+    }
+
+    /// Asserts that the transcript equals the provided snapshot.
+    ///
+    /// See the [`transcript`](MockTerminal::transcript) method for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the transcript is different from the snapshot.
+    #[track_caller]
+    pub fn assert_transcript(&self, snapshot: &str) {
+        let transcript = self.transcript();
+        if transcript != snapshot {
+            let mut msg = String::from("the transcript doesn't match the snapshot\n");
+            let _ = writeln!(msg, "--- snapshot\n{snapshot}");
+            msg.push_str(&self.fmt_transcript());
+            panic!("{msg}");
+        }
+    }
+
+    fn fmt_transcript(&self) -> String {
+        format!("--- transcript\n{}", self.transcript())
+    }
+}
+
+/// The input stream given to the prompts by the mock terminal.
+///
+/// The answers are copied into the transcript as soon as they are read.
+struct Input<'a> {
+    answers: &'a mut VecDeque<String>,
+    current: Vec<u8>,
+    pos: usize,
+    transcript: &'a RefCell<Vec<u8>>,
+}
+
+impl Read for Input<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl BufRead for Input<'_> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.pos >= self.current.len()
+            && let Some(answer) = self.answers.pop_front()
+        {
+            self.transcript
+                .borrow_mut()
+                .extend_from_slice(answer.as_bytes());
+            self.current = answer.into_bytes();
+            self.pos = 0;
+        }
+        Ok(&self.current[self.pos..])
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.pos = (self.pos + amount).min(self.current.len());
+    }
+}
+
+/// The output stream given to the prompts by the mock terminal.
+struct Output<'a> {
+    output: &'a mut Vec<u8>,
+    transcript: &'a RefCell<Vec<u8>>,
+}
+
+impl Write for Output<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.output.extend_from_slice(buf);
+        self.transcript.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MockTerminal;
+    use crate::prelude::*;
+
+    #[test]
+    fn transcript() -> anyhow::Result<()> {
+        let mut term = MockTerminal::new().answers(["foo", "bar", "3"]);
+        let (name, age) = term
+            .prompt(crate::written::<String>("Your name").then(crate::written::<u8>("Your age")))?;
+        assert_eq!(name, "foo");
+        assert_eq!(age, 3);
+        assert_eq!(term.remaining_answers(), 0);
+        term.assert_transcript(
+            "- Your name\n> foo\n- Your age\n> bar\n! invalid digit found in string\n> 3\n",
+        );
+        assert_eq!(
+            term.output(),
+            "- Your name\n> - Your age\n> ! invalid digit found in string\n> "
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn shown_prompts() -> anyhow::Result<()> {
+        let mut term = MockTerminal::new().answers(["4", "0", "1"]);
+        let license = term.prompt(
+            crate::selected("License", [("MIT", 1), ("GPL", 2)])
+                .fmt(crate::fmt().repeat_prompt(true)),
+        )?;
+        assert_eq!(license, 1);
+        term.assert_shown("- License", 3);
+        term.assert_shown("! choose a number between 1 and 2", 2);
+        assert_eq!(term.shown("[1] - MIT"), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn many_prompts() -> anyhow::Result<()> {
+        let mut term = MockTerminal::new().answer("yes");
+        assert!(term.prompt(crate::bool("Continue?"))?);
+        term.push_answer("no");
+        assert!(!term.prompt(crate::bool("Continue?"))?);
+        term.assert_shown("- Continue?", 2);
+        term.assert_transcript("- Continue?\n> yes\n- Continue?\n> no\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_answers() {
+        let mut term = MockTerminal::new().answer("foo");
+        let res = term.prompt(crate::written::<u8>("Your age"));
+        assert!(matches!(res, Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof));
+        term.assert_transcript("- Your age\n> foo\n! invalid digit found in string\n> ");
+    }
+
+    #[test]
+    #[should_panic = "\"- Your age\" was shown 1 time(s) instead of 2"]
+    fn assert_shown_fails() {
+        let mut term = MockTerminal::new().answer("3");
+        term.prompt(crate::written::<u8>("Your age")).unwrap();
+        term.assert_shown("- Your age", 2);
+    }
+
+    #[test]
+    #[should_panic = "the transcript doesn't match the snapshot"]
+    fn assert_transcript_fails() {
+        let mut term = MockTerminal::new().answer("3");
+        term.prompt(crate::written::<u8>("Your age")).unwrap();
+        term.assert_transcript("- Your age\n> 4\n");
+    }
+}